@@ -33,6 +33,13 @@ fn next_bench(c: &mut Criterion) {
     c.bench_function("next 100", |b| b.iter(|| black_box(P.next())));
 }
 
+fn next_via_half_bench(c: &mut Criterion) {
+    const P: Palindrome = Palindrome::closest(23347574332);
+    c.bench_function("next_via_half 100", |b| {
+        b.iter(|| black_box(P.next_via_half()))
+    });
+}
+
 fn le_bench(c: &mut Criterion) {
     c.bench_function("le 100", |b| {
         b.iter(|| {
@@ -63,6 +70,47 @@ fn is_palindrome_bench(c: &mut Criterion) {
     });
 }
 
+/// Deterministic pseudo-random values of every length, like externally-sourced numbers.
+fn unordered_values() -> Vec<u64> {
+    let mut x = 0x9E37_79B9_7F4A_7C15u64;
+    (0..100_000)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x >> (x % 64)
+        })
+        .collect()
+}
+
+fn is_palindrome_unordered_bench(c: &mut Criterion) {
+    let values = unordered_values();
+    let mut out = vec![false; values.len()];
+    c.bench_function("is_palindrome_unordered 100", |b| {
+        b.iter(|| {
+            for (value, out) in values.iter().zip(out.iter_mut()) {
+                *out = value.is_palindrome();
+            }
+            black_box(&out);
+        })
+    });
+}
+
+#[cfg(feature = "simd")]
+fn is_palindrome_batch_bench(c: &mut Criterion) {
+    let values = unordered_values();
+    let mut out = vec![false; values.len()];
+    c.bench_function("is_palindrome_batch 100", |b| {
+        b.iter(|| {
+            Palindrome::is_palindrome_batch(black_box(&values), &mut out);
+            black_box(&out);
+        })
+    });
+}
+
+#[cfg(not(feature = "simd"))]
+fn is_palindrome_batch_bench(_: &mut Criterion) {}
+
 fn iter_from_p_bench(c: &mut Criterion) {
     const START: Palindrome = Palindrome::closest(289734);
     const END: Palindrome = Palindrome::closest(2894545734);
@@ -98,7 +146,18 @@ fn iter_len_bench(c: &mut Criterion) {
 fn iter_iterate_bench(c: &mut Criterion) {
     const P: PalindromeIter = PalindromeIter::first_n_from(1_000, Palindrome::closest(1_000_000));
     c.bench_function("iter_iterate 100", |b| {
-        b.iter(|| black_box(P.for_each(|_| ())))
+        b.iter(|| {
+            P.for_each(|p| {
+                black_box(p);
+            })
+        })
+    });
+}
+
+fn iter_sum_values_bench(c: &mut Criterion) {
+    const P: PalindromeIter = PalindromeIter::first_n_from(83345654, Palindrome::closest(98723));
+    c.bench_function("iter_sum_values 100", |b| {
+        b.iter(|| black_box(P.sum_values()))
     });
 }
 
@@ -109,14 +168,18 @@ criterion_group!(
     to_n_bench,
     previous_bench,
     next_bench,
+    next_via_half_bench,
     le_bench,
     ge_bench,
     is_palindrome_bench,
+    is_palindrome_unordered_bench,
+    is_palindrome_batch_bench,
     iter_from_p_bench,
     iter_from_u64_bench,
     iter_first_n_bench,
     iter_first_n_from_bench,
     iter_len_bench,
     iter_iterate_bench,
+    iter_sum_values_bench,
 );
 criterion_main!(benches);
@@ -52,6 +52,44 @@
 //! And if you want, you can go from palindrome to palindrome with the
 //! [`Palindrome::previous`] and [`Palindrome::next`] functions.
 //!
+//! Almost everything is `const`, so palindromes can be generated at compile time:
+//! ```
+//! use palindromeda::Palindrome;
+//!
+//! const TABLE: [Palindrome; 4] = {
+//!     let mut table = [Palindrome::MIN; 4];
+//!     let mut idx = 0;
+//!     while idx < table.len() {
+//!         table[idx] = Palindrome::ge(10u64.pow(idx as u32 + 1));
+//!         idx += 1;
+//!     }
+//!     table
+//! };
+//! assert_eq!([11u64, 101, 1001, 10001], TABLE.map(u64::from));
+//! ```
+//!
+//! ## Arithmetic
+//! [`Palindrome`] supports the usual operators with [`u64`] and other palindromes,
+//! always returning a [`u64`]. Like with [`u64`] itself, overflow panics in debug builds
+//! and wraps in release builds. Enable the `strict-arithmetic` feature to panic on overflow
+//! in every build profile, or use checked methods like [`Palindrome::checked_add_u64`].
+//!
+//! The methods follow the naming of the integer types in [`std`]:
+//! - `checked_` returns [`None`] on overflow.
+//! - `saturating_` stops at the boundary instead.
+//! - `wrapping_` wraps around the boundary instead.
+//!
+//! The `_u64` methods ([`Palindrome::checked_add_u64`], [`Palindrome::saturating_add_u64`],
+//! [`Palindrome::wrapping_add_u64`] and so on) work on the value and return a [`u64`],
+//! bounded by [`u64`]. The `_n` methods ([`Palindrome::checked_next_n`],
+//! [`Palindrome::saturating_previous_n`], [`Palindrome::wrapping_next_n`] and so on)
+//! step through the palindromes themselves and return a [`Palindrome`], bounded by
+//! [`Palindrome::MIN`] and [`Palindrome::MAX`].
+//!
+//! The one exception to value arithmetic is `+=` and `-=` with a [`usize`] on a [`Palindrome`],
+//! which steps through the palindromes like [`Palindrome::saturating_next_n`] and
+//! [`Palindrome::saturating_previous_n`]. E.g.: `palindrome += 1` moves to the next palindrome.
+//!
 //! ## Iterating over palindromes
 //! With [`PalindromeIter`] you can iterate over a large swathe of palindromes.
 //! You can iterate over a custom range with [`PalindromeIter::from`] or
@@ -61,16 +99,31 @@
 //! a specific palindrome with [`PalindromeIter::first_n_from`].
 //! Be sure to use [`PalindromeIter::len`] for quickly determining the
 //! length of the iterator.
+//!
+//! ## Features
+//! All features are disabled by default.
+//! - `arbitrary`: implements [`arbitrary`](https://docs.rs/arbitrary)'s `Arbitrary`
+//!   for [`Palindrome`], for fuzzing.
+//! - `num-traits`: implements [`IsPalindrome`] for every unsigned
+//!   [`num-traits`](https://docs.rs/num-traits) primitive integer instead of only
+//!   [`u8`], [`u16`], [`u32`] and [`u64`].
+//! - `proptest`: implements [`proptest`](https://docs.rs/proptest)'s `Arbitrary`
+//!   for [`Palindrome`], shrinking towards [`Palindrome::MIN`].
+//! - `simd`: adds `Palindrome::is_palindrome_batch` for checking many values at once.
+//! - `strict-arithmetic`: panics on overflow in the operators in every build profile,
+//!   see [Arithmetic](#arithmetic).
 
 use forward_ref::{forward_ref_binop, forward_ref_op_assign, forward_ref_unop};
 use std::{
+    collections::VecDeque,
     fmt::Display,
+    io::{self, Write},
+    num::{NonZeroU64, TryFromIntError},
     ops::{
-        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, Div,
-        DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
-        SubAssign,
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound,
+        Deref, Div, DivAssign, Mul, MulAssign, Not, RangeBounds, Rem, RemAssign, Shl, ShlAssign,
+        Shr, ShrAssign, Sub, SubAssign,
     },
-    u64,
 };
 
 struct PalindromeDigits {
@@ -111,19 +164,110 @@ impl PalindromeDigits {
     }
 }
 
+/// The reason a value couldn't be turned into a [`Palindrome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PalindromeError {
+    /// There were no digits.
+    Empty,
+    /// A digit wasn't in the range `0-9`.
+    InvalidDigit(u8),
+    /// The number started with a `0` without being `0` itself.
+    LeadingZero,
+    /// The number isn't the same forwards and backwards.
+    NotPalindrome,
+    /// The number is larger than [`Palindrome::MAX`].
+    Overflow,
+}
+
+impl Display for PalindromeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no digits"),
+            Self::InvalidDigit(digit) => write!(f, "invalid digit {digit}"),
+            Self::LeadingZero => write!(f, "leading zero"),
+            Self::NotPalindrome => write!(f, "not a palindrome"),
+            Self::Overflow => write!(f, "larger than the largest palindrome"),
+        }
+    }
+}
+
+impl std::error::Error for PalindromeError {}
+
+/// A value was outside the range palindromes can be found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainError {
+    /// The boundary the value crossed.
+    pub boundary: u64,
+}
+
+impl Display for DomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value is outside the boundary {}", self.boundary)
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+/// The outcome of repeatedly reversing the digits of a number and adding them to it.
+///
+/// Returned by [`Palindrome::classify_reverse_add`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReverseAddResult {
+    /// A palindrome was reached after the given amount of steps.
+    Palindrome(Palindrome, usize),
+    /// The next sum would overflow [`u64`]. Contains the last number reached.
+    Overflowed(u64),
+    /// No palindrome was reached within the allowed amount of steps, like with
+    /// the suspected Lychrel number `196`.
+    Suspected,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Palindrome(u64);
 
+// Fail to compile if the hardcoded constants ever drift.
+const _: () = {
+    assert!(Palindrome::is_palindrome(Palindrome::MAX.0));
+    assert!(Palindrome::le(u64::MAX).0 == Palindrome::MAX.0);
+    assert!(Palindrome::MAX.next().0 == Palindrome::MAX.0);
+    assert!(Palindrome::MAX.to_n() == Palindrome::MAX_N);
+};
+
 impl Palindrome {
     pub const MIN: Self = Palindrome(0);
     /// The largest possible palindrome that can fit in a [`std::u64`].
     pub const MAX: Self = Palindrome(18_446_744_066_044_764_481);
+    /// Every single-digit palindrome, i.e. `0` through `9`.
+    pub const SINGLE_DIGITS: [Self; 10] = [
+        Palindrome(0),
+        Palindrome(1),
+        Palindrome(2),
+        Palindrome(3),
+        Palindrome(4),
+        Palindrome(5),
+        Palindrome(6),
+        Palindrome(7),
+        Palindrome(8),
+        Palindrome(9),
+    ];
     /// Length of the largest possible palindrome.
     const MAX_LEN: usize = 20;
     /// The 0-based index of the largest palindrome that can fit in a [`std::u64`].
     const MAX_N: usize = 11844674405;
 
+    /// Return the 0-based index of [`Self::MAX`], the largest index accepted by [`Self::nth`].
+    pub const fn max_index() -> usize {
+        Self::MAX_N
+    }
+
+    /// Return the amount of decimal digits in [`Self::max_index`], i.e. the width needed
+    /// to store any palindrome-index.
+    pub const fn max_index_digits() -> u32 {
+        Self::MAX_N.ilog10() + 1
+    }
+
     #[inline]
+    #[allow(clippy::manual_is_multiple_of)]
     const fn is_palindrome(mut x: u64) -> bool {
         if x % 10 == 0 && x != 0 {
             return false;
@@ -135,727 +279,4039 @@ impl Palindrome {
             x /= 10;
         }
 
-        return x == right_half || x == right_half / 10;
+        x == right_half || x == right_half / 10
     }
 
-    /// Return the palindrome closest to `x`.
+    /// Return whether `x` is a palindrome when padded with leading zeros to `width` digits.
+    /// E.g.: `110` isn't a palindrome, but `0110` is.
     ///
-    /// **NOTE:** If the closest palindrome is in both directions,
-    /// return the higher number. E.g.: `x=10` returns `11`.
-    pub const fn closest(x: u64) -> Self {
-        let ge = Self::ge(x);
-        let le = Self::le(x);
-        if ge.0 - x <= x - le.0 {
-            return ge;
+    /// **NOTE:** Returns `false` if `x` doesn't fit in `width` digits.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn is_palindrome_fixed_width(x: u64, width: u32) -> bool {
+        if x == 0 {
+            return width > 0;
         }
 
-        le
+        let length = x.ilog10() + 1;
+        if length > width {
+            return false;
+        }
+
+        // The leading zeros must be mirrored by as many trailing zeros.
+        let leading_zeros = width - length;
+        leading_zeros < length
+            && x % 10u64.pow(leading_zeros) == 0
+            && Self::is_palindrome(x / 10u64.pow(leading_zeros))
     }
 
-    /// Construct a palindrome from the first half of a digit and a provided length.
+    /// Return whether every digit of `x` and its mirrored digit sum to `9`.
+    /// E.g.: `1368` is a nine-complement (`1 + 8` and `3 + 6`), `1358` isn't.
     ///
-    /// NOTE: Will panic if `length` isn't `2x` or `2x - 1` the size of `digits_half.len()`.
-    const fn construct(digits_half: &PalindromeDigits, length: usize) -> Self {
-        // If we have a 5-digit number, then we construct by using
-        // the 1st, 2nd, 3rd, 2nd, and 1st elements.
-        // If we have a 6-digit number, then we construct by using
-        // the 1st, 2nd, 3rd, 3rd, 2nd, and 1st elements.
-        let second_half_length = length - digits_half.len();
-        let mut palindrome = 0;
-        let mut idx = 0; // first half idx
-        while idx < digits_half.len() {
-            palindrome *= 10;
-            palindrome += digits_half.get(idx) as u64;
-            idx += 1;
+    /// **NOTE:** Numbers with an odd amount of digits are never nine-complements,
+    /// since the middle digit would have to be `4.5`. So no palindrome is a nine-complement.
+    pub const fn is_nine_complement(x: u64) -> bool {
+        // `0` has a single digit, so it's covered by the odd length check.
+        let length = match x.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if length % 2 == 1 {
+            return false;
         }
-        idx = 1; // second half reverse idx
-        while idx <= second_half_length {
-            palindrome *= 10;
-            palindrome += digits_half.get(second_half_length - idx) as u64;
-            idx += 1;
+
+        let mut rest = x;
+        let mut divisor = 10u64.pow(length - 1);
+        while divisor > 0 {
+            if rest / divisor + rest % 10 != 9 {
+                return false;
+            }
+            rest = rest % divisor / 10; // Strip the outermost digits.
+            divisor /= 100;
         }
 
-        Palindrome(palindrome)
+        true
     }
 
-    /// Return a PalindromeDigits.
-    /// NOTE: Wasting stack since arrays can't have dynamic length.
-    const fn to_digits(mut x: u64) -> PalindromeDigits {
+    /// Return whether `x` becomes a palindrome by changing at most `max_edits` digits.
+    /// E.g.: `12320` becomes the palindrome `12321` by changing one digit.
+    ///
+    /// **NOTE:** Each pair of mirrored digits that differ needs exactly one edit,
+    /// and the amount of digits never changes.
+    pub const fn is_near_palindrome(x: u64, max_edits: u32) -> bool {
         let length = match x.checked_ilog10() {
-            Some(x) => x as usize + 1,
+            Some(exp) => exp + 1,
             None => 1,
         };
-        let mut arr: [u8; Self::MAX_LEN] = [0; Self::MAX_LEN];
-
-        let mut idx = 1;
-        while idx <= length {
-            arr[Self::MAX_LEN - idx] = (x % 10) as u8;
-            x /= 10;
-            idx += 1;
+        let mut edits = 0;
+        let mut rest = x;
+        let mut divisor = 10u64.pow(length - 1);
+        while divisor > 0 {
+            if rest / divisor != rest % 10 {
+                edits += 1;
+                if edits > max_edits {
+                    return false;
+                }
+            }
+            rest = rest % divisor / 10; // Strip the outermost digits.
+            divisor /= 100;
         }
 
-        PalindromeDigits::from(arr, length)
+        true
     }
 
-    /// Return the nth palindrome (0-based indexing).
+    /// Return whether `x` is a palindrome when written in `base`.
+    /// E.g.: `9` is `1001` in base 2, so it's a palindrome in base 2.
     ///
-    /// **NOTE:** Returns [`None`] if the palindrome is larger than [`Self::MAX`].
-    pub const fn nth(n: usize) -> Option<Self> {
-        if n > Self::MAX_N {
-            return None;
+    /// **NOTE:** Will panic if `base` is below `2`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn is_palindrome_in_base(mut x: u64, base: u32) -> bool {
+        assert!(base >= 2, "base must be at least 2");
+        let base = base as u64;
+        if x % base == 0 && x != 0 {
+            return false;
         }
 
-        // 10th number (9 on 0-based indexing) is an edge case.
-        if n < 10 {
-            return Some(Self(n as u64));
+        let mut right_half = 0;
+        while x > right_half {
+            right_half = right_half * base + x % base;
+            x /= base;
         }
 
-        let mut n_copy = n;
-        let mut n_digits = 1;
-        while n_digits <= Self::MAX_N {
-            // n_digits = 2
-            if n_copy < PalindromeIter::palindromes_in_n_digits(n_digits as u8) {
-                // Remove the palindromes below n-digit palindromes.
-                n_copy -= PalindromeIter::palindromes_in_n_digits(n_digits as u8 - 1);
-                let first_n_digits = n_digits.div_ceil(2);
-                let first_half = 10u64.pow(first_n_digits as u32 - 1) + n_copy as u64;
-                let digits_half = Self::to_digits(first_half);
+        x == right_half || x == right_half / base
+    }
 
-                return Some(Self::construct(&digits_half, n_digits));
+    /// Return every base in which `x` has at least two digits and is a palindrome, in ascending
+    /// order. E.g.: `10` returns `[3, 4, 9]`, since `10` is `101` in base 3, `22` in base 4
+    /// and `11` in base 9.
+    ///
+    /// **NOTE:** Every number is a single digit, and so trivially a palindrome, in every base
+    /// above itself. Those bases are excluded, so only bases from `2` up to `x` are checked.
+    /// Bases above [`u32::MAX`] are excluded as well.
+    ///
+    /// **ATTENTION:** Takes time proportional to the square root of `x`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn palindromic_bases_nontrivial(x: u64) -> Vec<u32> {
+        let root = Self::isqrt(x as u128) as u64;
+        let mut small_bases = Vec::new();
+        let mut large_bases = Vec::new();
+
+        // Above the square root `x` has exactly two digits, `d` and `d`, so `x == d * (base + 1)`
+        // with `d < base`. Every such `d` is at most the square root.
+        for d in 1..=root {
+            if d >= 2 && Self::is_palindrome_in_base(x, d as u32) {
+                small_bases.push(d as u32);
+            }
+            if x % d == 0 {
+                let base = x / d - 1;
+                if base > root && d < base && base <= u32::MAX as u64 {
+                    large_bases.push(base as u32);
+                }
             }
-            n_digits += 1;
         }
 
-        None
+        large_bases.reverse();
+        small_bases.extend(large_bases);
+        small_bases
     }
 
-    /// Return the `n` value of [`Self`].
-    ///
-    /// Opposite of [`Self::nth`].
-    pub const fn to_n(&self) -> usize {
-        PalindromeIter::len_from_0(self.0)
+    /// Return whether `x` is a palindrome when written in base 36 (`0-9` and `a-z`).
+    /// E.g.: `1333` is `111` in base 36, so it's a base-36 palindrome.
+    pub const fn is_base36_palindrome(x: u64) -> bool {
+        Self::is_palindrome_in_base(x, 36)
     }
 
-    /// Return the previous palindromic number.
+    /// Return the smallest number greater than or equal to `x` that's a palindrome in base 36
+    /// (see [`Self::is_base36_palindrome`]). E.g.: `x=36` (`10` in base 36) returns `37` (`11`).
     ///
-    /// **NOTE:** Lowest return-value is [`Self::MIN`].
-    pub const fn previous(&self) -> Self {
-        Self::le(self.0 - 1)
+    /// **ATTENTION:** Highest return-value is `18446744073401514579` (`3w5e11211e5w3` in base 36),
+    /// the largest base-36 palindrome that fits in a [`u64`].
+    pub const fn ge_base36(x: u64) -> u64 {
+        const MAX_BASE36: u64 = 18_446_744_073_401_514_579;
+        match Self::ge_in_base(x, 36) {
+            Some(y) => y,
+            None => MAX_BASE36,
+        }
     }
 
-    /// Return the next palindromic number.
+    /// Return the number closest to `x` that's a palindrome when written in `radix`
+    /// (see [`Self::is_palindrome_in_base`]).
+    /// E.g.: `x=10` (`1010` in radix 2) returns `9` (`1001`).
     ///
-    /// **NOTE:** Highest return-value is [`Self::MAX`].
-    pub const fn next(&self) -> Self {
-        Self::ge(self.0 + 1)
+    /// **NOTE:** Like [`Self::closest`], if the closest palindrome is in both directions,
+    /// return the higher number. E.g.: `x=6` (`110` in radix 2) returns `7` (`111`).
+    ///
+    /// **ATTENTION:** If no palindrome in `radix` at or above `x` fits in a [`u64`], return the
+    /// largest one that does. Will panic if `radix` is below `2`.
+    pub const fn closest_radix(x: u64, radix: u32) -> u64 {
+        assert!(radix >= 2, "radix must be at least 2");
+        let radix = radix as u64;
+
+        let le = Self::le_in_base(x, radix);
+        match Self::ge_in_base(x, radix) {
+            Some(ge) if ge - x <= x - le => ge,
+            _ => le,
+        }
     }
 
-    /// Return the first palindromic number that is less than or equal to `x`.
-    pub const fn le(x: u64) -> Self {
-        if Palindrome::is_palindrome(x) {
-            return Palindrome(x);
+    /// Return the smallest number greater than or equal to `x` that's a palindrome in `base`,
+    /// or [`None`] if it doesn't fit in a [`u64`].
+    const fn ge_in_base(x: u64, base: u64) -> Option<u64> {
+        // Least significant digit first.
+        let mut digits = [0; 64];
+        let mut length = 0;
+        let mut rest = x;
+        loop {
+            digits[length] = rest % base;
+            rest /= base;
+            length += 1;
+            if rest == 0 {
+                break;
+            }
         }
 
-        let mut digits = Self::to_digits(x);
-        let mut length = digits.len();
-        let half_length = length.div_ceil(2); // As in amount of digits.
-        let mut fh_idx = half_length - 1;
-        let mut sh_idx = half_length;
-        if length % 2 == 1 {
-            sh_idx -= 1; // We don't want center value of uneven number.
+        match Self::mirror_in_base(&mut digits, length, base) {
+            Some(y) if y >= x => return Some(y),
+            Some(_) => {}
+            None => return None, // A larger first half can't fit either.
         }
 
-        let mut skip = 0;
+        // The mirror is too small, so increment the first half.
+        let mut idx = length / 2;
+        while idx < length && digits[idx] == base - 1 {
+            digits[idx] = 0;
+            idx += 1;
+        }
+        if idx == length {
+            // Every digit was the largest digit, so the next palindrome has one more digit.
+            return match base.checked_pow(length as u32) {
+                Some(power) => power.checked_add(1),
+                None => None,
+            };
+        }
+        digits[idx] += 1;
+
+        Self::mirror_in_base(&mut digits, length, base)
+    }
+
+    /// Return the largest number less than or equal to `x` that's a palindrome in `base`.
+    const fn le_in_base(x: u64, base: u64) -> u64 {
+        // Least significant digit first.
+        let mut digits = [0; 64];
+        let mut length = 0;
+        let mut rest = x;
         loop {
-            // 100 -> 99
-            // 372 -> 363
-            // 4847 -> 4774
-            // 4003 -> 3993
-            if digits.get(fh_idx) < digits.get(sh_idx) {
-                digits.narrow_start_end(0, half_length);
-                return Self::construct(&digits, length);
-            }
-            if digits.get(fh_idx) > digits.get(sh_idx) {
-                // First try to downgrade center value, if it's 0, set to 9 and continue.
-                // Once non-0 value found, -- it.
-                let center_idx = half_length - 1; // Center idx.
-                let mut i = 0;
-                while i < half_length {
-                    let current_digit = digits.get_mut(center_idx - i);
-                    if *current_digit == 0 {
-                        *current_digit = 9;
-                        i += 1;
-                        continue;
-                    }
-                    *current_digit -= 1;
-                    // EDGE CASE: 100 -> 99 (length of first half digits CHANGES).
-                    // EDGE CASE: 10 -> 9 (length of first half digits DOESN'T CHANGE).
-                    if center_idx - i == 0 && *current_digit == 0 {
-                        *current_digit = 9;
-                        if length % 2 == 1 {
-                            skip += 1;
-                        }
-                        length -= 1; // Length always decreases by 1.
-                    }
-                    break;
-                }
-                digits.narrow_start_end(skip, half_length);
-                return Self::construct(&digits, length);
+            digits[length] = rest % base;
+            rest /= base;
+            length += 1;
+            if rest == 0 {
+                break;
             }
+        }
 
-            fh_idx -= 1;
-            sh_idx += 1;
+        match Self::mirror_in_base(&mut digits, length, base) {
+            Some(y) if y <= x => return y,
+            _ => {} // Too large, even if it doesn't fit in a `u64`.
+        }
+
+        // The mirror is too large, so decrement the first half. The mirror of a single digit is
+        // the digit itself, so there are at least two digits here.
+        let mut idx = length / 2;
+        while digits[idx] == 0 {
+            digits[idx] = base - 1;
+            idx += 1;
+        }
+        digits[idx] -= 1;
+        if digits[length - 1] == 0 {
+            // The first half was `10..0`, so the previous palindrome has one digit less,
+            // and every one of its digits is the largest digit.
+            return base.pow(length as u32 - 1) - 1;
+        }
+
+        match Self::mirror_in_base(&mut digits, length, base) {
+            Some(y) => y,
+            None => unreachable!(), // Smaller than `x`.
         }
     }
 
-    /// Return the first palindromic number that is greater than or equal to `x`.
-    ///
-    /// **ATTENTION:** Any value above [`Self::MAX`] will return [`Self::MAX`].
-    pub const fn ge(x: u64) -> Self {
-        if x >= Self::MAX.0 {
-            return Self::MAX;
+    /// Mirror the first half of `digits` (least significant digit first) over the second half,
+    /// and return the value, or [`None`] if it doesn't fit in a [`u64`].
+    const fn mirror_in_base(digits: &mut [u64; 64], length: usize, base: u64) -> Option<u64> {
+        let mut idx = 0;
+        while idx < length / 2 {
+            digits[idx] = digits[length - 1 - idx];
+            idx += 1;
         }
 
-        if Palindrome::is_palindrome(x) {
-            return Palindrome(x);
+        let mut y: u64 = 0;
+        let mut idx = length;
+        while idx > 0 {
+            idx -= 1;
+            y = match y.checked_mul(base) {
+                Some(y) => match y.checked_add(digits[idx]) {
+                    Some(y) => y,
+                    None => return None,
+                },
+                None => return None,
+            };
         }
 
-        let mut digits = Self::to_digits(x);
-        let length = digits.len();
-        let half_length = length.div_ceil(2); // As in amount of digits.
-        let mut fh_idx = half_length - 1;
-        let mut sh_idx = half_length;
-        if length % 2 == 1 {
-            fh_idx -= 1; // We don't want center value of uneven number.
+        Some(y)
+    }
+
+    /// Return the palindrome made of the digits of `left_half`, then `center` (if any),
+    /// then the digits of `left_half` reversed.
+    /// E.g.: `left_half=12` returns `12321` with `center=Some(3)` and `1221` with `center=None`.
+    ///
+    /// **NOTE:** Returns [`None`] if `left_half` is `0` (the palindrome would start with a `0`),
+    /// if `center` isn't a digit or if the palindrome is larger than [`Self::MAX`].
+    pub const fn from_parts(left_half: u64, center: Option<u8>) -> Option<Self> {
+        if left_half == 0 {
+            return None; // Leading zero.
         }
 
-        loop {
-            if digits.get(fh_idx) > digits.get(sh_idx) {
-                digits.narrow_start_end(0, half_length);
-                return Self::construct(&digits, length);
-            }
-            if digits.get(fh_idx) < digits.get(sh_idx) {
-                // First try to upgrade center value, if it's 9, set to 0 and continue.
-                // Once non-9 value found, ++ it. 999 is palindrome and can't happen.
-                let center_idx = half_length - 1; // Center idx.
-                let mut i = 0;
-                while i < half_length {
-                    let current_digit = digits.get_mut(center_idx - i);
-                    if *current_digit == 9 {
-                        *current_digit = 0;
-                        i += 1;
-                        continue;
-                    }
-                    *current_digit += 1;
-                    break;
-                }
-                digits.narrow_start_end(0, half_length);
-                return Self::construct(&digits, length);
+        let mut x = left_half;
+        if let Some(center) = center {
+            if center > 9 {
+                return None;
             }
-
-            fh_idx -= 1;
-            sh_idx += 1;
+            x = match x.checked_mul(10) {
+                Some(x) => match x.checked_add(center as u64) {
+                    Some(x) => x,
+                    None => return None,
+                },
+                None => return None,
+            };
         }
+        let mut rest = left_half;
+        while rest > 0 {
+            x = match x.checked_mul(10) {
+                Some(x) => match x.checked_add(rest % 10) {
+                    Some(x) => x,
+                    None => return None,
+                },
+                None => return None,
+            };
+            rest /= 10;
+        }
+
+        // Every palindrome that fits in a `u64` is at most `MAX`.
+        Some(Palindrome(x))
     }
-}
 
-impl Deref for Palindrome {
-    type Target = u64;
+    /// Return the number whose digits in `radix` are the palindrome with the first half `half`,
+    /// most significant digit first. The center digit is the last digit of `half` if `odd`.
+    /// E.g.: `half=[1, 0]` in radix 2 is `1001` when even and `101` when odd, so `9` and `5`.
+    ///
+    /// **NOTE:** Returns [`None`] if `half` is empty, if a digit isn't below `radix`,
+    /// if the palindrome would start with a `0` (except for `0` itself) or if the number
+    /// doesn't fit in a [`u64`]. Will panic if `radix` is below `2`.
+    pub const fn from_half_digits_radix(half: &[u8], odd: bool, radix: u32) -> Option<u64> {
+        assert!(radix >= 2, "radix must be at least 2");
+        if half.is_empty() {
+            return None;
+        }
+        if half[0] == 0 && (half.len() > 1 || !odd) {
+            return None; // Leading zero.
+        }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
+        let radix = radix as u64;
+        let mut x: u64 = 0;
+        let mut idx = 0;
+        while idx < half.len() {
+            if half[idx] as u64 >= radix {
+                return None;
+            }
+            x = match x.checked_mul(radix) {
+                Some(x) => match x.checked_add(half[idx] as u64) {
+                    Some(x) => x,
+                    None => return None,
+                },
+                None => return None,
+            };
+            idx += 1;
+        }
+        // Mirror the first half, skipping the center digit if there is one.
+        let mut idx = if odd { half.len() - 1 } else { half.len() };
+        while idx > 0 {
+            idx -= 1;
+            x = match x.checked_mul(radix) {
+                Some(x) => match x.checked_add(half[idx] as u64) {
+                    Some(x) => x,
+                    None => return None,
+                },
+                None => return None,
+            };
+        }
 
-impl Display for Palindrome {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        Some(x)
     }
-}
 
-impl From<Palindrome> for u64 {
-    fn from(value: Palindrome) -> Self {
-        value.0
+    /// Return whether `x` is a palindrome in every base from `2` up to and including `max_base`.
+    ///
+    /// **NOTE:** Every number is a single digit, and therefore a palindrome,
+    /// in the bases above it, so those bases aren't checked.
+    pub const fn is_palindrome_up_to_base(x: u64, max_base: u32) -> bool {
+        let mut base = 2;
+        while base <= max_base && (base as u64) <= x {
+            if !Self::is_palindrome_in_base(x, base) {
+                return false;
+            }
+            base += 1;
+        }
+
+        true
     }
-}
 
-impl From<&Palindrome> for u64 {
-    fn from(value: &Palindrome) -> Self {
-        value.0
+    /// Return whether `digits` reads the same forwards and backwards.
+    /// E.g.: `[1, 2, 3, 2, 1]` is a palindrome, `[1, 2, 3]` isn't.
+    ///
+    /// **NOTE:** The elements aren't checked to be decimal digits, and an empty slice
+    /// is a palindrome.
+    pub const fn digits_are_palindrome(digits: &[u8]) -> bool {
+        let mut i = 0;
+        while i < digits.len() / 2 {
+            if digits[i] != digits[digits.len() - 1 - i] {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
     }
-}
 
-impl PartialEq<u64> for Palindrome {
-    fn eq(&self, other: &u64) -> bool {
-        self.0 == *other
+    /// Return the absolute difference between [`Self`] and `x`.
+    ///
+    /// Unlike subtraction, this never underflows, no matter which value is larger.
+    pub fn abs_diff<T: Into<u64>>(&self, x: T) -> u64 {
+        self.0.abs_diff(x.into())
     }
-}
 
-impl PartialEq<Palindrome> for u64 {
-    fn eq(&self, other: &Palindrome) -> bool {
-        *self == other.0
+    /// Return `self + rhs`, or [`None`] if the addition would overflow.
+    ///
+    /// **NOTE:** Like the `+` operator, the output is a [`u64`]. This is also why [`Self`]
+    /// can't implement `num_traits::CheckedAdd`, which requires the output to be [`Self`].
+    pub const fn checked_add_u64(&self, rhs: u64) -> Option<u64> {
+        self.0.checked_add(rhs)
     }
-}
 
-impl PartialOrd<u64> for Palindrome {
-    fn ge(&self, other: &u64) -> bool {
-        self.0 >= *other
+    /// Return `self - rhs`, or [`None`] if the subtraction would underflow.
+    pub const fn checked_sub_u64(&self, rhs: u64) -> Option<u64> {
+        self.0.checked_sub(rhs)
     }
 
-    fn gt(&self, other: &u64) -> bool {
-        self.0 > *other
+    /// Return `self * rhs`, or [`None`] if the multiplication would overflow.
+    pub const fn checked_mul_u64(&self, rhs: u64) -> Option<u64> {
+        self.0.checked_mul(rhs)
     }
 
-    fn le(&self, other: &u64) -> bool {
-        self.0 <= *other
+    /// Return `self * rhs` as a [`u128`], which can never overflow.
+    pub const fn mul_u128(&self, rhs: u64) -> u128 {
+        self.0 as u128 * rhs as u128
     }
 
-    fn lt(&self, other: &u64) -> bool {
-        self.0 < *other
+    /// Return `self - rhs`, or `0` if the subtraction would underflow.
+    pub const fn saturating_sub_u64(&self, rhs: u64) -> u64 {
+        self.0.saturating_sub(rhs)
     }
 
-    fn partial_cmp(&self, other: &u64) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+    /// Return `self + rhs`, or [`u64::MAX`] if the addition would overflow.
+    pub const fn saturating_add_u64(&self, rhs: u64) -> u64 {
+        self.0.saturating_add(rhs)
     }
-}
 
-impl PartialOrd<Palindrome> for u64 {
-    fn ge(&self, other: &Palindrome) -> bool {
-        *self >= other.0
+    /// Return `self * rhs`, or [`u64::MAX`] if the multiplication would overflow.
+    pub const fn saturating_mul_u64(&self, rhs: u64) -> u64 {
+        self.0.saturating_mul(rhs)
     }
 
-    fn gt(&self, other: &Palindrome) -> bool {
-        *self > other.0
+    /// Return `self + rhs`, wrapping around at the boundary of [`u64`].
+    ///
+    /// **NOTE:** Never panics, not even with the `strict-arithmetic` feature.
+    pub const fn wrapping_add_u64(&self, rhs: u64) -> u64 {
+        self.0.wrapping_add(rhs)
     }
 
-    fn le(&self, other: &Palindrome) -> bool {
-        *self <= other.0
+    /// Return `self - rhs`, wrapping around at the boundary of [`u64`].
+    ///
+    /// **NOTE:** Never panics, not even with the `strict-arithmetic` feature.
+    pub const fn wrapping_sub_u64(&self, rhs: u64) -> u64 {
+        self.0.wrapping_sub(rhs)
     }
 
-    fn lt(&self, other: &Palindrome) -> bool {
-        *self < other.0
+    /// Return `self * rhs`, wrapping around at the boundary of [`u64`].
+    ///
+    /// **NOTE:** Never panics, not even with the `strict-arithmetic` feature.
+    pub const fn wrapping_mul_u64(&self, rhs: u64) -> u64 {
+        self.0.wrapping_mul(rhs)
     }
 
-    fn partial_cmp(&self, other: &Palindrome) -> Option<std::cmp::Ordering> {
-        self.partial_cmp(&other.0)
+    /// Return the [`Ordering`](std::cmp::Ordering) between [`Self`] and `other`.
+    ///
+    /// Unlike [`PartialOrd::partial_cmp`], this doesn't wrap the result in an [`Option`],
+    /// since comparing with a [`u64`] is always possible.
+    pub const fn cmp_u64(&self, other: &u64) -> std::cmp::Ordering {
+        if self.0 < *other {
+            std::cmp::Ordering::Less
+        } else if self.0 > *other {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
     }
-}
 
-impl Add<u64> for Palindrome {
-    type Output = u64;
+    /// Return the amount of palindromes in `range`. E.g.: `100..=999` returns `90`.
+    ///
+    /// **NOTE:** Any kind of range works, and an unbounded end counts up to and including
+    /// [`Self::MAX`]. Like [`PalindromeIter::len`], this doesn't iterate.
+    pub fn count(range: impl RangeBounds<u64>) -> usize {
+        let (start, end) = Self::half_open(&range);
+        if start >= end {
+            return 0;
+        }
 
-    #[inline]
-    fn add(self, rhs: u64) -> Self::Output {
-        self.0 + rhs
+        PalindromeIter::len_from_0(end) - PalindromeIter::len_from_0(start)
     }
-}
 
-forward_ref_binop!(impl Add, add for Palindrome, u64);
-
-impl Add<Palindrome> for u64 {
-    type Output = u64;
+    /// Return `range` as the half-open range `start..end`, with `end` at most
+    /// one past [`Self::MAX`].
+    fn half_open(range: &impl RangeBounds<u64>) -> (u64, u64) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.min(Self::MAX.0) + 1,
+            Bound::Excluded(&end) => end.min(Self::MAX.0 + 1),
+            Bound::Unbounded => Self::MAX.0 + 1,
+        };
 
-    #[inline]
-    fn add(self, rhs: Palindrome) -> Self::Output {
-        self + rhs.0
+        (start, end)
     }
-}
 
-forward_ref_binop!(impl Add, add for u64, Palindrome);
+    /// Return the amount of palindromes in the range `from..to` with an odd and with an even
+    /// amount of digits, as `(odd, even)`. E.g.: `0..1000` returns `(100, 9)`.
+    ///
+    /// **NOTE:** Like [`Self::count`], this doesn't iterate.
+    pub fn count_odd_even_in_range(from: u64, to: u64) -> (usize, usize) {
+        let (mut odd, mut even) = (0, 0);
+        let mut length = 1;
+        while length <= Self::MAX_LEN as u32 {
+            let lowest = if length == 1 {
+                0
+            } else {
+                10u64.pow(length - 1)
+            };
+            let highest = 10u64.checked_pow(length).unwrap_or(u64::MAX);
+            let count = Self::count(lowest.max(from)..highest.min(to));
+            if length % 2 == 1 {
+                odd += count;
+            } else {
+                even += count;
+            }
+            length += 1;
+        }
 
-impl AddAssign<Palindrome> for u64 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Palindrome) {
-        *self += rhs.0;
+        (odd, even)
     }
-}
 
-forward_ref_op_assign!(impl AddAssign, add_assign for u64, Palindrome);
+    /// Return the amount of palindromes less than or equal to `max` that start
+    /// (and therefore end) with `digit`.
+    ///
+    /// **NOTE:** Only the palindrome `0` starts with `0`. Will panic if `digit` is above `9`.
+    pub const fn count_starting_with(digit: u8, max: u64) -> usize {
+        assert!(digit <= 9, "digit must be 0-9");
+        if digit == 0 {
+            return 1;
+        }
 
-impl BitAnd<u64> for Palindrome {
-    type Output = u64;
+        // No palindromes between MAX and u64::MAX, and constructing them would overflow.
+        let max = if max > Self::MAX.0 { Self::MAX.0 } else { max };
+        let digits = Self::to_digits(max);
+        let length = digits.len();
 
-    #[inline]
-    fn bitand(self, rhs: u64) -> Self::Output {
-        self.0 & rhs
-    }
-}
+        // Every shorter palindrome starting with `digit`.
+        let mut count = 0;
+        let mut n_digits = 1;
+        while n_digits < length {
+            count += 10usize.pow(n_digits.div_ceil(2) as u32 - 1);
+            n_digits += 1;
+        }
 
-forward_ref_binop!(impl BitAnd, bitand for Palindrome, u64);
+        // Palindromes as long as `max` are counted by their first half.
+        let half_length = length.div_ceil(2);
+        let mut first_half = 0;
+        let mut idx = 0;
+        while idx < half_length {
+            first_half = first_half * 10 + digits.get(idx) as usize;
+            idx += 1;
+        }
+        let mut half_digits = digits;
+        half_digits.narrow_start_end(0, half_length);
+        let own_too_large = match Self::construct(&half_digits, length) {
+            Some(p) => p.0 > max,
+            None => true,
+        };
+        if own_too_large {
+            first_half -= 1; // Own first half makes a palindrome above `max`.
+        }
 
-impl BitAnd<Palindrome> for u64 {
-    type Output = u64;
+        let unit = 10usize.pow(half_length as u32 - 1);
+        let lowest = digit as usize * unit;
+        let highest = lowest + unit - 1;
+        if first_half >= lowest {
+            count += if first_half < highest {
+                first_half
+            } else {
+                highest
+            } - lowest
+                + 1;
+        }
 
-    #[inline]
-    fn bitand(self, rhs: Palindrome) -> Self::Output {
-        self & rhs.0
+        count
     }
-}
 
-forward_ref_binop!(impl BitAnd, bitand for u64, Palindrome);
+    /// Return an iterator over all palindromes ordered by their distance to `x`, nearest first.
+    /// E.g.: `x=150` yields `151`, `141`, `161`, `131`, ...
+    ///
+    /// **NOTE:** If two palindromes are equally far from `x`, the higher one is yielded first.
+    pub fn iter_around(x: u64) -> impl Iterator<Item = Self> {
+        let mut below = Some(Self::le(x));
+        let mut above = if Self::is_palindrome(x) {
+            Self::ge_beyond(x)
+        } else {
+            Some(Self::ge(x))
+        };
+        if x > Self::MAX.0 {
+            above = None;
+        }
 
-impl BitAndAssign<Palindrome> for u64 {
-    #[inline]
-    fn bitand_assign(&mut self, rhs: Palindrome) {
-        *self &= rhs.0
+        std::iter::from_fn(move || {
+            let take_above = match (below, above) {
+                (Some(b), Some(a)) => a.0 - x <= x - b.0,
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (None, None) => return None,
+            };
+
+            if take_above {
+                let palindrome = above?;
+                above = Self::ge_beyond(palindrome.0);
+                Some(palindrome)
+            } else {
+                let palindrome = below?;
+                below = if palindrome.0 == 0 {
+                    None
+                } else {
+                    Some(palindrome.previous())
+                };
+                Some(palindrome)
+            }
+        })
     }
-}
 
-forward_ref_op_assign!(impl BitAndAssign, bitand_assign for u64, Palindrome);
-
-impl BitOr<u64> for Palindrome {
-    type Output = u64;
+    /// Return the first palindromic number that is greater than `x`, or [`None`] past [`Self::MAX`].
+    const fn ge_beyond(x: u64) -> Option<Self> {
+        if x >= Self::MAX.0 {
+            return None;
+        }
 
-    #[inline]
-    fn bitor(self, rhs: u64) -> Self::Output {
-        self.0 | rhs
+        Some(Self::ge(x + 1))
     }
-}
 
-forward_ref_binop!(impl BitOr, bitor for Palindrome, u64);
+    /// Return the palindrome closest to `x`.
+    ///
+    /// **NOTE:** If the closest palindrome is in both directions,
+    /// return the higher number. E.g.: `x=10` returns `11`.
+    ///
+    /// **ATTENTION:** Any value above [`Self::MAX`] will return [`Self::MAX`].
+    /// Use [`Self::try_closest`] to handle those values explicitly.
+    pub const fn closest(x: u64) -> Self {
+        if x >= Self::MAX.0 {
+            return Self::MAX;
+        }
 
-impl BitOr<Palindrome> for u64 {
-    type Output = u64;
+        let ge = Self::ge(x);
+        let le = Self::le(x);
+        if ge.0 - x <= x - le.0 {
+            return ge;
+        }
 
-    #[inline]
-    fn bitor(self, rhs: Palindrome) -> Self::Output {
-        self | rhs.0
+        le
     }
-}
 
-forward_ref_binop!(impl BitOr, bitor for u64, Palindrome);
+    /// Return the palindrome closest to `x`, like [`Self::closest`], together with
+    /// how it was chosen:
+    /// - [`Ordering::Less`](std::cmp::Ordering::Less): the palindrome below `x` was strictly closer.
+    /// - [`Ordering::Greater`](std::cmp::Ordering::Greater): the palindrome above `x` was strictly closer.
+    /// - [`Ordering::Equal`](std::cmp::Ordering::Equal): both were equally far, so the higher one was chosen.
+    ///
+    /// E.g.: `x=10` returns `(11, Equal)`, since `9` and `11` are both `1` away.
+    ///
+    /// **NOTE:** If `x` is a palindrome, it's both the palindrome below and above `x`,
+    /// so `(x, Equal)` is returned.
+    ///
+    /// **ATTENTION:** Any value above [`Self::MAX`] will return `(MAX, Less)`.
+    pub const fn closest_detailed(x: u64) -> (Self, std::cmp::Ordering) {
+        if x > Self::MAX.0 {
+            return (Self::MAX, std::cmp::Ordering::Less);
+        }
 
-impl BitOrAssign<Palindrome> for u64 {
-    #[inline]
-    fn bitor_assign(&mut self, rhs: Palindrome) {
-        *self |= rhs.0
+        let ge = Self::ge(x);
+        let le = Self::le(x);
+        let above = ge.0 - x;
+        let below = x - le.0;
+        if above < below {
+            (ge, std::cmp::Ordering::Greater)
+        } else if above > below {
+            (le, std::cmp::Ordering::Less)
+        } else {
+            (ge, std::cmp::Ordering::Equal)
+        }
     }
-}
 
-forward_ref_op_assign!(impl BitOrAssign, bitor_assign for u64, Palindrome);
+    /// Return the palindrome with as many digits as `x` that differs from `x` in the fewest
+    /// digits, by mirroring the first half of `x` onto the second half.
+    /// E.g.: `12399` returns `12321` (2 digits differ), while [`Self::closest`] returns `12421`.
+    ///
+    /// **NOTE:** The result always has the same amount of digits as `x`, just like with
+    /// [`Self::closest`], but it can be further away in value. E.g.: `19_999` returns
+    /// `19_991`, while [`Self::closest`] returns `20_002`.
+    ///
+    /// **ATTENTION:** If mirroring would be larger than [`Self::MAX`], [`Self::MAX`] is returned.
+    pub const fn closest_by_digit_distance(x: u64) -> Self {
+        let mut digits = Self::to_digits(x);
+        let length = digits.len();
+        digits.narrow_start_end(0, length.div_ceil(2));
 
-impl BitXor<u64> for Palindrome {
-    type Output = u64;
+        Self::construct_saturating(&digits, length)
+    }
 
-    #[inline]
-    fn bitxor(self, rhs: u64) -> Self::Output {
-        self.0 ^ rhs
+    /// Return the palindrome in `set` closest to `target`. E.g.: `target=130` and
+    /// `set=[11, 121, 141]` returns `121`.
+    ///
+    /// **NOTE:** `set` must be sorted in ascending order, since it's binary searched.
+    /// Ties are resolved like [`Self::closest`], and an empty `set` returns [`None`].
+    pub fn closest_in(target: u64, set: &[Palindrome]) -> Option<&Palindrome> {
+        let idx = set.partition_point(|palindrome| palindrome.0 < target);
+        match (idx.checked_sub(1).map(|below| &set[below]), set.get(idx)) {
+            (Some(below), Some(above)) if target - below.0 < above.0 - target => Some(below),
+            (_, Some(above)) => Some(above),
+            (below, None) => below,
+        }
     }
-}
 
-forward_ref_binop!(impl BitXor, bitxor for Palindrome, u64);
+    /// Return the palindrome closest (see [`Self::closest`]) to `numerator / denominator`
+    /// of `scale`. E.g.: `numerator=1`, `denominator=3` and `scale=1000` returns `333`.
+    ///
+    /// **NOTE:** Returns [`None`] if `denominator` is `0`. The product is computed without
+    /// overflowing, and the quotient is rounded down before looking for the closest palindrome.
+    pub const fn closest_ratio(numerator: u64, denominator: u64, scale: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
 
-impl BitXor<Palindrome> for u64 {
-    type Output = u64;
+        let x = scale as u128 * numerator as u128 / denominator as u128;
+        if x > u64::MAX as u128 {
+            return Some(Self::MAX);
+        }
 
-    #[inline]
-    fn bitxor(self, rhs: Palindrome) -> Self::Output {
-        self ^ rhs.0
+        Some(Self::closest(x as u64))
     }
-}
-
-forward_ref_binop!(impl BitXor, bitxor for u64, Palindrome);
 
-impl BitXorAssign<Palindrome> for u64 {
-    #[inline]
-    fn bitxor_assign(&mut self, rhs: Palindrome) {
-        *self ^= rhs.0
+    /// Return the palindrome closest to `x` (see [`Self::closest`]) together with the
+    /// palindromes right below and above it, as `(below, closest, above)`.
+    /// E.g.: `x=120` returns `(111, 121, 131)`.
+    ///
+    /// **NOTE:** If the closest palindrome is [`Self::MIN`] or [`Self::MAX`], there's no
+    /// palindrome below or above it respectively, so the closest palindrome is returned there.
+    /// E.g.: `x=0` returns `(0, 0, 1)`.
+    pub const fn closest_with_neighbors(x: u64) -> (Self, Self, Self) {
+        let closest = Self::closest(x);
+        (closest.previous(), closest, closest.next())
     }
-}
-
-forward_ref_op_assign!(impl BitXorAssign, bitxor_assign for u64, Palindrome);
 
-impl Div<u64> for Palindrome {
-    type Output = u64;
+    /// Return the palindrome closest to `x` (see [`Self::closest`]) among the palindromes
+    /// at most `k` indices away from `p`. E.g.: `p=121`, `x=500` and `k=2` returns `141`.
+    ///
+    /// **NOTE:** The index window is clamped to [`Self::MIN`] and [`Self::MAX`].
+    /// Ties go to the higher palindrome, just like [`Self::closest`].
+    pub const fn closest_near(p: Palindrome, x: u64, k: usize) -> Palindrome {
+        let n = p.to_n();
+        let lowest = match Self::nth(n.saturating_sub(k)) {
+            Some(palindrome) => palindrome,
+            None => unreachable!(),
+        };
+        let highest = match Self::nth(n.saturating_add(k)) {
+            Some(palindrome) => palindrome,
+            None => Self::MAX,
+        };
 
-    #[inline]
-    fn div(self, rhs: u64) -> Self::Output {
-        self.0 / rhs
+        // Palindromes grow with their index, so clamping the closest palindrome to the window
+        // gives the closest palindrome in the window.
+        let closest = Self::closest(x);
+        if closest.0 < lowest.0 {
+            lowest
+        } else if closest.0 > highest.0 {
+            highest
+        } else {
+            closest
+        }
     }
-}
 
-forward_ref_binop!(impl Div, div for Palindrome, u64);
+    /// Return whether `a` and `b` have the same closest palindrome (see [`Self::closest`]).
+    pub const fn same_basin(a: u64, b: u64) -> bool {
+        Self::closest(a).0 == Self::closest(b).0
+    }
 
-impl Div<Palindrome> for u64 {
-    type Output = u64;
+    /// Return the range `start..end` of every number whose closest palindrome (see [`Self::closest`])
+    /// is the same as `x`'s. E.g.: `x=20` returns `(17, 28)`, since every number in `17..28` is
+    /// closest to `22`.
+    ///
+    /// **NOTE:** The basin of [`Self::MAX`] also includes [`u64::MAX`], which the range can't contain.
+    pub const fn basin_of(x: u64) -> (u64, u64) {
+        let palindrome = Self::closest(x);
+
+        // Ties go to the higher palindrome, so the midpoint below belongs to this basin,
+        // while the midpoint above belongs to the next one.
+        let start = if palindrome.0 == Self::MIN.0 {
+            0
+        } else {
+            let previous = palindrome.previous().0;
+            previous + (palindrome.0 - previous).div_ceil(2)
+        };
+        let end = if palindrome.0 == Self::MAX.0 {
+            u64::MAX
+        } else {
+            let next = palindrome.next().0;
+            palindrome.0 + (next - palindrome.0).div_ceil(2)
+        };
 
-    #[inline]
-    fn div(self, rhs: Palindrome) -> Self::Output {
-        self / rhs.0
+        (start, end)
     }
-}
-
-forward_ref_binop!(impl Div, div for u64, Palindrome);
 
-impl DivAssign<Palindrome> for u64 {
-    #[inline]
-    fn div_assign(&mut self, rhs: Palindrome) {
-        *self /= rhs.0
+    /// Return a key for sorting numbers by their closest palindrome (see [`Self::closest`]),
+    /// then by their distance to it. E.g.: `x=20` returns `(22, 2)`.
+    ///
+    /// **NOTE:** Numbers at the same distance on either side of their palindrome get the same key,
+    /// so a stable sort like [`slice::sort_by_key`] keeps them in their original order.
+    pub const fn sort_key(x: u64) -> (u64, u64) {
+        let closest = Self::closest(x);
+        (closest.0, closest.0.abs_diff(x))
     }
-}
 
-forward_ref_op_assign!(impl DivAssign, div_assign for u64, Palindrome);
+    /// Return the trajectory of repeatedly replacing a palindrome `p` by `closest(p + 1)`,
+    /// starting at `closest(start)` and applying the step at most `steps` times.
+    /// E.g.: `start=7` and `steps=5` returns `[7, 8, 9, 11]`.
+    ///
+    /// **NOTE:** The trajectory stops early at the first palindrome that maps to itself.
+    /// Every palindrome from `11` up is such a fixed point, except the ones made of only `9`s:
+    /// for those, `p + 1` is exactly halfway to the next palindrome and the tie resolves upwards.
+    /// E.g.: `start=99` returns `[99, 101]`. The trajectory is never longer than `11` palindromes.
+    pub fn iterate_closest(start: u64, steps: usize) -> Vec<Self> {
+        let mut palindrome = Self::closest(start);
+        let mut trajectory = vec![palindrome];
+        for _ in 0..steps {
+            let next = Self::closest(palindrome.0.saturating_add(1));
+            if next == palindrome {
+                break;
+            }
+            trajectory.push(next);
+            palindrome = next;
+        }
 
-impl Mul<u64> for Palindrome {
-    type Output = u64;
+        trajectory
+    }
 
-    #[inline]
-    fn mul(self, rhs: u64) -> Self::Output {
-        self.0 * rhs
+    /// Return the memory representation of [`Self`] as a byte array in big-endian byte order.
+    pub const fn to_be_bytes(&self) -> [u8; 8] {
+        self.0.to_be_bytes()
     }
-}
 
-forward_ref_binop!(impl Mul, mul for Palindrome, u64);
+    /// Return the memory representation of [`Self`] as a byte array in little-endian byte order.
+    pub const fn to_le_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
 
-impl Mul<Palindrome> for u64 {
-    type Output = u64;
+    /// Return the palindrome from its representation as a byte array in big-endian byte order.
+    ///
+    /// **NOTE:** Returns [`None`] if the bytes don't represent a palindrome.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Option<Self> {
+        let x = u64::from_be_bytes(bytes);
+        if Self::is_palindrome(x) {
+            return Some(Palindrome(x));
+        }
 
-    #[inline]
-    fn mul(self, rhs: Palindrome) -> Self::Output {
-        self * rhs.0
+        None
     }
-}
 
-forward_ref_binop!(impl Mul, mul for u64, Palindrome);
+    /// Return the palindrome from its representation as a byte array in little-endian byte order.
+    ///
+    /// **NOTE:** Returns [`None`] if the bytes don't represent a palindrome.
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Option<Self> {
+        let x = u64::from_le_bytes(bytes);
+        if Self::is_palindrome(x) {
+            return Some(Palindrome(x));
+        }
 
-impl MulAssign<Palindrome> for u64 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: Palindrome) {
-        *self *= rhs.0
+        None
     }
-}
-
-forward_ref_op_assign!(impl MulAssign, mul_assign for u64, Palindrome);
 
-impl Not for Palindrome {
-    type Output = u64;
+    /// Return the palindrome closest to `x`, like [`Self::closest`].
+    ///
+    /// **NOTE:** Returns [`Err`] if `x` is above [`Self::MAX`].
+    pub const fn try_closest(x: u64) -> Result<Self, DomainError> {
+        if x > Self::MAX.0 {
+            return Err(DomainError {
+                boundary: Self::MAX.0,
+            });
+        }
 
-    #[inline]
-    fn not(self) -> Self::Output {
-        !self.0
+        Ok(Self::closest(x))
     }
-}
 
-forward_ref_unop!(impl Not, not for Palindrome);
+    /// Return the palindrome closest to `x`, and whether `x` already was that palindrome.
+    /// E.g.: `121` returns `(121, true)` and `123` returns `(121, false)`.
+    pub const fn coerce(x: u64) -> (Self, bool) {
+        if Self::is_palindrome(x) {
+            return (Self(x), true);
+        }
 
-impl Rem<u64> for Palindrome {
-    type Output = u64;
+        (Self::closest(x), false)
+    }
 
-    #[inline]
-    fn rem(self, rhs: u64) -> Self::Output {
-        self.0 % rhs
+    /// Return the largest palindrome below `10^exp` and the smallest palindrome at or above it.
+    /// E.g.: `exp=3` returns `(999, 1001)`.
+    ///
+    /// **NOTE:** Returns [`None`] if `exp` is above `19`, since no palindrome is at or above
+    /// `10^20`.
+    pub const fn around_power_of_ten(exp: u32) -> Option<(Self, Self)> {
+        if exp >= Self::MAX_LEN as u32 {
+            return None;
+        }
+
+        let power = 10u64.pow(exp);
+        Some((Self::le(power - 1), Self::ge(power)))
     }
-}
 
-forward_ref_binop!(impl Rem, rem for Palindrome, u64);
+    /// Return the palindrome with the digits of `outer` on both sides of the digits of `inner`.
+    /// E.g.: `outer=12321` and `inner=7` returns `12321712321`, and `outer=11` and `inner=454`
+    /// returns `1145411`.
+    ///
+    /// **NOTE:** Both pieces are used whole, no matter whether they have an odd or even amount
+    /// of digits, so the result has `2 * outer_length + inner_length` digits.
+    /// Returns [`None`] if `outer` is `0`, since the result would start with a zero,
+    /// or if the result is larger than [`Self::MAX`].
+    pub const fn nest(outer: &Palindrome, inner: &Palindrome) -> Option<Self> {
+        if outer.0 == 0 {
+            return None;
+        }
 
-impl Rem<Palindrome> for u64 {
-    type Output = u64;
+        let outer_length = outer.0.ilog10() + 1;
+        let inner_length = match inner.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if 2 * outer_length + inner_length > Self::MAX_LEN as u32 {
+            return None;
+        }
 
-    #[inline]
-    fn rem(self, rhs: Palindrome) -> Self::Output {
-        self % rhs.0
+        let nested = outer.0 as u128 * 10u128.pow(outer_length + inner_length)
+            + inner.0 as u128 * 10u128.pow(outer_length)
+            + outer.0 as u128;
+        if nested > Self::MAX.0 as u128 {
+            return None;
+        }
+
+        Some(Self(nested as u64))
     }
-}
 
-forward_ref_binop!(impl Rem, rem for u64, Palindrome);
+    /// Return the palindrome with `count` copies of the digit `insert` inserted at the mirror axis
+    /// of [`Self`], so the outer digits stay the same and the result has `count` more digits.
+    /// E.g.: `1221` with `insert=0` and `count=3` returns `1200021`.
+    ///
+    /// **NOTE:** If [`Self`] has an odd amount of digits, the mirror axis runs through its middle
+    /// digit, which stays in the middle: half of the copies go on either side of it.
+    /// E.g.: `121` with `insert=0` and `count=2` returns `10201`.
+    ///
+    /// Returns [`None`] if `insert` isn't a digit, if the copies can't be split evenly around the
+    /// middle digit (an odd `count` with an odd amount of digits), if the result would start
+    /// with a zero (a single digit palindrome expanded by zeros) or if the result is larger than
+    /// [`Self::MAX`].
+    pub const fn expand_center(&self, insert: u8, count: usize) -> Option<Self> {
+        if insert > 9 {
+            return None;
+        }
+        if count == 0 {
+            return Some(*self);
+        }
 
-impl RemAssign<Palindrome> for u64 {
-    #[inline]
-    fn rem_assign(&mut self, rhs: Palindrome) {
-        *self %= rhs.0
-    }
-}
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp as usize + 1,
+            None => 1,
+        };
+        let odd = length % 2 == 1;
+        if (odd && count % 2 == 1) || (length == 1 && insert == 0) {
+            return None;
+        }
+        if count > Self::MAX_LEN - length {
+            return None;
+        }
 
-forward_ref_op_assign!(impl RemAssign, rem_assign for u64, Palindrome);
+        // The inserted digits, plus the middle digit for odd lengths, replace the middle of
+        // `self` between the digits of both halves.
+        let half_power = 10u128.pow((length / 2) as u32);
+        let middle_length = (length % 2 + count) as u32;
+        let left = self.0 as u128 / half_power / 10u128.pow(length as u32 % 2);
+        let right = self.0 as u128 % half_power;
+        let mut middle = insert as u128 * (10u128.pow(middle_length) - 1) / 9;
+        if odd {
+            let center = (self.0 as u128 / half_power) % 10;
+            let axis = 10u128.pow(count as u32 / 2);
+            middle = middle - insert as u128 * axis + center * axis;
+        }
 
-impl Shl<u64> for Palindrome {
-    type Output = u64;
+        let expanded = (left * 10u128.pow(middle_length) + middle) * half_power + right;
+        if expanded > Self::MAX.0 as u128 {
+            return None;
+        }
 
-    #[inline]
-    fn shl(self, rhs: u64) -> Self::Output {
-        self.0 << rhs
+        Some(Self(expanded as u64))
     }
-}
 
-forward_ref_binop!(impl Shl, shl for Palindrome, u64);
+    /// Return [`Self`] as an [`f64`].
+    ///
+    /// **NOTE:** [`f64`] only represents integers up to `2^53` exactly, so larger palindromes
+    /// are rounded to the nearest representable value, which is usually not a palindrome.
+    pub const fn to_f64(&self) -> f64 {
+        self.0 as f64
+    }
 
-impl Shl<Palindrome> for u64 {
-    type Output = u64;
+    /// Return [`Self`] as a [`u32`].
+    ///
+    /// **NOTE:** Returns [`None`] if the palindrome is larger than [`u32::MAX`].
+    pub const fn to_u32(&self) -> Option<u32> {
+        if self.0 > u32::MAX as u64 {
+            return None;
+        }
 
-    #[inline]
-    fn shl(self, rhs: Palindrome) -> Self::Output {
-        self << rhs.0
+        Some(self.0 as u32)
     }
-}
 
-forward_ref_binop!(impl Shl, shl for u64, Palindrome);
+    /// Return [`Self`] as a [`u16`].
+    ///
+    /// **NOTE:** Returns [`None`] if the palindrome is larger than [`u16::MAX`].
+    pub const fn to_u16(&self) -> Option<u16> {
+        if self.0 > u16::MAX as u64 {
+            return None;
+        }
 
-impl ShlAssign<Palindrome> for u64 {
-    #[inline]
-    fn shl_assign(&mut self, rhs: Palindrome) {
-        *self <<= rhs.0;
+        Some(self.0 as u16)
     }
-}
-
-forward_ref_op_assign!(impl ShlAssign, shl_assign for u64, Palindrome);
 
-impl Shr<u64> for Palindrome {
-    type Output = u64;
+    /// Return [`Self`] as a [`u8`].
+    ///
+    /// **NOTE:** Returns [`None`] if the palindrome is larger than [`u8::MAX`].
+    pub const fn to_u8(&self) -> Option<u8> {
+        if self.0 > u8::MAX as u64 {
+            return None;
+        }
 
-    #[inline]
-    fn shr(self, rhs: u64) -> Self::Output {
-        self.0 >> rhs
+        Some(self.0 as u8)
     }
-}
-
-forward_ref_binop!(impl Shr, shr for Palindrome, u64);
 
-impl Shr<Palindrome> for u64 {
-    type Output = u64;
+    /// Return [`Self`] as a [`NonZeroU64`].
+    ///
+    /// **NOTE:** Returns [`None`] only for the palindrome `0`.
+    pub const fn to_nonzero(&self) -> Option<NonZeroU64> {
+        NonZeroU64::new(self.0)
+    }
 
-    #[inline]
-    fn shr(self, rhs: Palindrome) -> Self::Output {
-        self >> rhs.0
+    /// Return the amount of bits needed to store [`Self`], i.e. the position of its highest
+    /// set bit. E.g.: `5` (`101` in binary) returns `3`.
+    ///
+    /// **NOTE:** `0` returns `0`, since it has no set bits.
+    pub const fn bit_length(&self) -> u32 {
+        u64::BITS - self.0.leading_zeros()
     }
-}
 
-forward_ref_binop!(impl Shr, shr for u64, Palindrome);
+    /// Return whether [`Self`] fits in a [`u32`], i.e. whether [`Self::to_u32`] returns [`Some`].
+    pub const fn fits_in_u32(&self) -> bool {
+        self.bit_length() <= u32::BITS
+    }
 
-impl ShrAssign<Palindrome> for u64 {
-    #[inline]
-    fn shr_assign(&mut self, rhs: Palindrome) {
-        *self >>= rhs.0;
+    /// Return whether [`Self`] fits in a [`u16`], i.e. whether [`Self::to_u16`] returns [`Some`].
+    pub const fn fits_in_u16(&self) -> bool {
+        self.bit_length() <= u16::BITS
     }
-}
 
-forward_ref_op_assign!(impl ShrAssign, shr_assign for u64, Palindrome);
+    /// Return the position of [`Self`] among all palindromes as a fraction from `0.0`
+    /// ([`Self::MIN`]) to `1.0` ([`Self::MAX`]), based on its palindrome-index.
+    pub fn index_fraction(&self) -> f64 {
+        self.to_n() as f64 / Self::MAX_N as f64
+    }
 
-impl Sub<u64> for Palindrome {
-    type Output = u64;
+    /// Return the palindrome at the position `f` among all palindromes, where `0.0` is
+    /// [`Self::MIN`] and `1.0` is [`Self::MAX`]. Opposite of [`Self::index_fraction`].
+    ///
+    /// **NOTE:** The palindrome-index is rounded to the nearest integer, and `f` is clamped
+    /// to `0.0..=1.0`. Returns [`None`] if `f` is NaN.
+    pub fn from_fraction(f: f64) -> Option<Self> {
+        if f.is_nan() {
+            return None;
+        }
 
-    #[inline]
-    fn sub(self, rhs: u64) -> Self::Output {
-        self.0 - rhs
+        Self::nth((f.clamp(0.0, 1.0) * Self::MAX_N as f64).round() as usize)
     }
-}
-
-forward_ref_binop!(impl Sub, sub for Palindrome, u64);
 
-impl Sub<Palindrome> for u64 {
-    type Output = u64;
+    /// Return the palindrome closest to `x` rounded to the nearest integer.
+    /// E.g.: `x=120.6` returns `121`.
+    ///
+    /// **NOTE:** Returns [`None`] if `x` is NaN, infinite, negative or too large for a [`u64`].
+    /// Any value above `2^53` has lost precision already, so the result is the palindrome
+    /// closest to the [`f64`], not to the number it was converted from.
+    pub fn closest_from_f64(x: f64) -> Option<Self> {
+        let rounded = x.round();
+        // `u64::MAX as f64` is `2^64`, which is already too large.
+        if !rounded.is_finite() || rounded < 0.0 || rounded >= u64::MAX as f64 {
+            return None;
+        }
 
-    #[inline]
-    fn sub(self, rhs: Palindrome) -> Self::Output {
-        self - rhs.0
+        Some(Self::closest(rounded as u64))
     }
-}
 
-forward_ref_binop!(impl Sub, sub for u64, Palindrome);
+    /// Return the distinct cyclic rotations of the digits of [`Self`] that are palindromes,
+    /// ordered by how many digits were moved from the front to the back, starting with [`Self`].
+    /// E.g.: `1221` returns `[1221, 2112]`, since `2211` and `1122` aren't palindromes.
+    ///
+    /// **NOTE:** Rotations with a leading zero (e.g. `0110` from `1001`) and rotations larger
+    /// than [`Self::MAX`] are excluded.
+    pub fn rotations(&self) -> Vec<Self> {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        let mut rotations = vec![*self];
+        for moved in 1..length {
+            let unit = 10u64.pow(length - moved);
+            let (front, back) = (self.0 / unit, self.0 % unit);
+            if back < unit / 10 {
+                continue; // Leading zero.
+            }
+            let rotated = back as u128 * 10u128.pow(moved) + front as u128;
+            if rotated > Self::MAX.0 as u128 || !Self::is_palindrome(rotated as u64) {
+                continue;
+            }
+            if !rotations.contains(&Self(rotated as u64)) {
+                rotations.push(Self(rotated as u64));
+            }
+        }
 
-impl SubAssign<Palindrome> for u64 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Palindrome) {
-        *self -= rhs.0
+        rotations
     }
-}
 
-forward_ref_op_assign!(impl SubAssign, sub_assign for u64, Palindrome);
+    /// Return the palindrome whose first half is the reverse of [`Self`]'s first half.
+    /// E.g.: `12321` (first half `123`) returns `32123` (first half `321`).
+    ///
+    /// **NOTE:** Returns [`None`] if the reversed first half has a leading zero
+    /// (e.g. `12021`, since `120` reversed is `021`) or if the palindrome is larger
+    /// than [`Self::MAX`].
+    pub const fn reflect(&self) -> Option<Self> {
+        let digits = Self::to_digits(self.0);
+        let length = digits.len();
+        let half_length = length.div_ceil(2);
+        if digits.get(half_length - 1) == 0 && self.0 != 0 {
+            return None;
+        }
 
-pub struct PalindromeIter {
-    from: Palindrome,
-    to: u64,
-}
+        let mut reversed_half = 0;
+        let mut idx = half_length;
+        while idx > 0 {
+            reversed_half = reversed_half * 10 + digits.get(idx - 1) as u64;
+            idx -= 1;
+        }
 
-impl PalindromeIter {
-    /// Return an iterator over all palindromes in the range `from..to`.
+        Self::construct(&Self::to_digits(reversed_half), length)
+    }
+
+    /// Return `x` as a [`Palindrome`]. E.g.: `1221` returns `Ok(1221)`.
     ///
-    /// **NOTE:** [`std::iter::Step`] is currently nightly/experimental,
-    /// so this will have to do for now.
-    pub fn from<T: Into<u64>, U: Into<u64>>(from: T, to: U) -> Self {
-        Self {
-            from: Palindrome::ge(from.into()),
-            to: to.into(),
+    /// **NOTE:** Returns [`PalindromeError::NotPalindrome`] if `x` isn't a palindrome.
+    /// This is the same as `Palindrome::try_from(x)`.
+    pub const fn new(x: u64) -> Result<Self, PalindromeError> {
+        if Self::is_palindrome(x) {
+            return Ok(Palindrome(x));
         }
+
+        Err(PalindromeError::NotPalindrome)
     }
 
-    /// Return an iterator over the first `n` palindromes.
+    /// Return `x` as a [`Palindrome`] without checking whether it's a palindrome.
     ///
-    /// **NOTE:** Any palindrome larger than [`Palindrome::MAX`] won't be included
-    /// and will instead be [`None`].
-    pub const fn first_n(n: usize) -> Self {
-        Self::first_n_from(n, Palindrome(0))
+    /// # Safety
+    /// The caller must guarantee that `x` is a palindrome (see [`IsPalindrome::is_palindrome`]).
+    /// Every other function assumes [`Self`] holds a palindrome, so breaking this
+    /// guarantee leads to wrong results or panics.
+    pub const unsafe fn from_u64_unchecked(x: u64) -> Self {
+        debug_assert!(Self::is_palindrome(x));
+        Palindrome(x)
     }
 
-    /// Return an iterator over the first `n` palindromes from the first palindrome `from`.
+    /// Return the palindrome closest to `x` that is divisible by `k`.
     ///
-    /// **NOTE:** Any palindrome larger than [`Palindrome::MAX`] won't be included
-    /// and will instead be [`None`].
-    pub const fn first_n_from(n: usize, from: Palindrome) -> Self {
-        let to = match Palindrome::nth(from.to_n() + n) {
-            Some(p) => p.0,
-            None => Palindrome::MAX.0,
-        };
+    /// **NOTE:** Returns [`None`] if `k` is `0`. Since `0` is divisible by everything,
+    /// any other `k` always finds a palindrome. Ties are resolved like [`Self::closest`].
+    ///
+    /// **ATTENTION:** The search goes through every palindrome between `x` and the result,
+    /// and `0` bounds it to the palindromes within `x` of `x`: roughly `2 * sqrt(x)` of them,
+    /// e.g. about `2 * 10^6` around `10^12`. Only a `k` with few palindrome multiples,
+    /// like a large power of two, gets near that bound. A `k` divisible by `10` returns `0`
+    /// straight away, since no other palindrome ends in `0`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn closest_multiple_of(x: u64, k: u64) -> Option<Self> {
+        if k == 0 {
+            return None;
+        }
 
-        Self { from, to }
+        // Every positive multiple of `k` is further away from `x` than `0` is,
+        // and no palindrome but `0` is a multiple of `10`.
+        if k / 2 > x || k % 10 == 0 {
+            return Some(Self::MIN);
+        }
+
+        Self::iter_around(x).find(|p| p.0 % k == 0)
     }
 
-    /// Return the length of [`Self`].
+    /// Return the even palindrome closest to `x`. E.g.: `x=1001` returns `898`.
     ///
-    /// **NOTE:** This function is constant time and much faster than [`Self::count`] for any non-trivial range.
-    pub const fn len(&self) -> usize {
-        // Calculate length from 0..self.from
-        let over_counted = Self::len_from_0(self.from.0);
+    /// **NOTE:** Ties are resolved like [`Self::closest`]. Any value above the largest
+    /// even palindrome (`8999999999999999998`) returns that palindrome.
+    pub const fn closest_even(x: u64) -> Self {
+        Self::closest_with_parity(x, 0)
+    }
 
-        // Calculate length from 0..self.to
-        let over_count = Self::len_from_0(self.to);
+    /// Return the odd palindrome closest to `x`. E.g.: `x=2002` returns `1991`.
+    ///
+    /// **NOTE:** Ties are resolved like [`Self::closest`]. Any value below `1` returns `1`,
+    /// and any value above [`Self::MAX`] returns [`Self::MAX`].
+    pub const fn closest_odd(x: u64) -> Self {
+        Self::closest_with_parity(x, 1)
+    }
+
+    /// Return the palindrome closest to `x` whose remainder when divided by `2` is `remainder`.
+    const fn closest_with_parity(x: u64, remainder: u64) -> Self {
+        // A palindrome ends with its first digit, so its parity only changes
+        // between blocks of palindromes with the same first digit.
+        let mut below = Some(Self::le(x));
+        while let Some(palindrome) = below {
+            if palindrome.0 % 2 == remainder {
+                break;
+            }
+            below = if palindrome.0 == 0 {
+                None
+            } else {
+                // Jump to the largest palindrome with a lower first digit.
+                let magnitude = 10u64.pow(palindrome.0.ilog10());
+                Some(Self::le(palindrome.0 / magnitude * magnitude - 1))
+            };
+        }
+
+        let mut above = if x > Self::MAX.0 {
+            None
+        } else {
+            Some(Self::ge(x))
+        };
+        while let Some(palindrome) = above {
+            if palindrome.0 % 2 == remainder {
+                break;
+            }
+            // Jump to the smallest palindrome with a higher first digit.
+            let magnitude = match palindrome.0.checked_ilog10() {
+                Some(exp) => 10u64.pow(exp),
+                None => 1,
+            };
+            above = match (palindrome.0 / magnitude + 1).checked_mul(magnitude) {
+                Some(block) if block <= Self::MAX.0 => Some(Self::ge(block)),
+                _ => None,
+            };
+        }
 
-        return over_count - over_counted;
+        match (below, above) {
+            (Some(b), Some(a)) => {
+                if a.0 - x <= x - b.0 {
+                    a
+                } else {
+                    b
+                }
+            }
+            (Some(b), None) => b,
+            (None, Some(a)) => a,
+            // `0` is even and `Self::MAX` is odd, so one direction always has a palindrome.
+            (None, None) => unreachable!(),
+        }
     }
 
-    // Doesn't include `to`.
-    const fn len_from_0(to: u64) -> usize {
-        if to == 0 {
-            return 0;
+    /// Return whether all digits of [`Self`] are equal. E.g.: `777` returns `true`.
+    ///
+    /// **NOTE:** Every single-digit palindrome is a repdigit.
+    pub const fn is_repdigit(&self) -> bool {
+        let last_digit = self.0 % 10;
+        let mut x = self.0 / 10;
+        while x > 0 {
+            if x % 10 != last_digit {
+                return false;
+            }
+            x /= 10;
         }
 
-        let digits = Palindrome::to_digits(to);
-        let half_length = digits.len().div_ceil(2);
+        true
+    }
 
-        let mut count = Self::palindromes_in_n_digits(digits.len() as u8) as isize;
-        let mut front_part_as_num = 0isize;
-        let mut to_subtract = 1isize;
-        let mut idx = 0;
-        while idx < half_length {
-            to_subtract *= 10;
-            front_part_as_num *= 10;
-            front_part_as_num += digits.get(idx) as isize;
-            idx += 1;
+    /// Return whether [`Self`] is a Fibonacci number. E.g.: `55` returns `true`.
+    ///
+    /// **NOTE:** Uses the fact that `n` is a Fibonacci number if and only if
+    /// `5n² + 4` or `5n² - 4` is a perfect square.
+    pub const fn is_fibonacci(&self) -> bool {
+        let n = self.0 as u128;
+        let plus_four = match (n * n).checked_mul(5) {
+            Some(x) => x.checked_add(4),
+            None => None,
+        };
+        match plus_four {
+            Some(x) => Self::is_square(x) || (x >= 8 && Self::is_square(x - 8)),
+            // The only Fibonacci number this large that fits in a `u64` is
+            // `12_200_160_415_121_876_738`, which isn't a palindrome.
+            None => false,
         }
-        count += front_part_as_num - to_subtract;
+    }
 
-        // If second half of the number is higher than first half, +1.
-        let (mut i, mut j) = (half_length, half_length + 1);
-        if digits.len() % 2 == 1 {
-            i -= 1; // Don't want to compare center value of uneven digit number.
+    /// Return the integer square root of `x`, like `u128::isqrt` (which needs Rust 1.84).
+    const fn isqrt(x: u128) -> u128 {
+        if x < 2 {
+            return x;
         }
-        // Find the first digits from center and out that differ.
-        while i > 0 && digits.get(i - 1) == digits.get(j - 1) {
-            i -= 1;
-            j += 1;
+
+        // Start at a power of two above the root, so Newton's method descends onto it.
+        let mut root: u128 = 1 << (128 - x.leading_zeros()).div_ceil(2);
+        loop {
+            let next = (root + x / root) / 2;
+            if next >= root {
+                return root;
+            }
+            root = next;
         }
-        if i > 0 && digits.get(i - 1) < digits.get(j - 1) {
-            count += 1; // Second half is larger, so ++ that bi***.
+    }
+
+    const fn is_square(x: u128) -> bool {
+        let root = Self::isqrt(x);
+        root * root == x
+    }
+
+    /// Return every Fibonacci number that is a palindrome and fits in a [`u64`], in ascending order.
+    /// E.g.: the first few are `0`, `1`, `2`, `3`, `5`, `8` and `55`.
+    ///
+    /// **NOTE:** `1` appears twice in the Fibonacci sequence, but only once here.
+    pub fn fibonacci_palindromes() -> Vec<Self> {
+        let mut palindromes = vec![Self(0), Self(1)];
+        let (mut a, mut b) = (1u64, 2u64);
+        loop {
+            if Self::is_palindrome(b) {
+                palindromes.push(Self(b));
+            }
+            (a, b) = match a.checked_add(b) {
+                Some(next) => (b, next),
+                None => break,
+            };
         }
 
-        return count as usize;
+        palindromes
     }
 
-    // Will crash if n > Self::MAX_N.
-    const fn palindromes_in_n_digits(n: u8) -> usize {
-        const N_DIGIT_NUMBER_PALINDROME: [usize; 21] = [
-            0,
-            10,
+    /// Return whether [`Self`] doubled is also a palindrome. E.g.: `121` returns `true` (`242`),
+    /// but `5` returns `false` (`10`).
+    ///
+    /// **NOTE:** Returns `false` if doubling [`Self`] overflows [`u64`].
+    pub const fn is_palindrome_doubled(&self) -> bool {
+        match self.0.checked_mul(2) {
+            Some(doubled) => Self::is_palindrome(doubled),
+            None => false,
+        }
+    }
+
+    /// Return the palindrome made by adding `k` to every digit of [`Self`], modulo 10.
+    /// E.g.: `191` with `k=1` returns `202`, since no digit carries over into the next.
+    ///
+    /// **NOTE:** Returns [`None`] if the first digit would become `0` (e.g. `989` with `k=1`
+    /// would be `090`) or if the palindrome is larger than [`Self::MAX`].
+    /// Shifting every digit equally always keeps the digits symmetric.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn digit_shift(&self, k: u8) -> Option<Self> {
+        let k = (k % 10) as u64;
+        // The first digit is the same as the last one, so it must not become `0`.
+        if self.0 > 9 && (self.0 % 10 + k) % 10 == 0 {
+            return None;
+        }
+
+        let mut x = self.0 / 10;
+        let mut shifted = ((self.0 % 10 + k) % 10) as u128;
+        let mut unit = 1u128;
+        while x > 0 {
+            unit *= 10;
+            shifted += ((x % 10 + k) % 10) as u128 * unit;
+            x /= 10;
+        }
+        if shifted > Self::MAX.0 as u128 {
+            return None;
+        }
+
+        Some(Self(shifted as u64))
+    }
+
+    /// Return the nth repdigit (0-based indexing), where repdigits are the palindromes
+    /// with all digits equal. E.g.: `n=0` returns `0`, `n=10` returns `11` and `n=19` returns `111`.
+    ///
+    /// **NOTE:** Returns [`None`] if the repdigit is larger than [`Self::MAX`].
+    pub const fn nth_repdigit(n: usize) -> Option<Self> {
+        if n == 0 {
+            return Some(Self::MIN);
+        }
+
+        // After `0`, every length has the repdigits `1..1` to `9..9`.
+        let length = ((n - 1) / 9 + 1) as u32;
+        let digit = ((n - 1) % 9 + 1) as u128;
+        if length > Self::MAX_LEN as u32 {
+            return None;
+        }
+
+        let repdigit = digit * (10u128.pow(length) - 1) / 9;
+        if repdigit > Self::MAX.0 as u128 {
+            return None;
+        }
+
+        Some(Self(repdigit as u64))
+    }
+
+    /// Return the digits of [`Self`] in an array of `N` digits, left-padded with zeros.
+    /// E.g.: `121` with `N=5` returns `[0, 0, 1, 2, 1]`.
+    ///
+    /// **NOTE:** Returns [`Err`] with the amount of digits in [`Self`] if they don't fit in `N`.
+    pub const fn to_digit_array<const N: usize>(&self) -> Result<[u8; N], usize> {
+        let digits = Self::to_digits(self.0);
+        let length = digits.len();
+        if length > N {
+            return Err(length);
+        }
+
+        let mut array = [0; N];
+        let mut idx = 0;
+        while idx < length {
+            array[N - length + idx] = digits.get(idx);
+            idx += 1;
+        }
+
+        Ok(array)
+    }
+
+    /// Return the digit of [`Self`] at position `index`, where `0` is the most significant digit.
+    /// E.g.: `12321` with `index=1` returns `Some(2)`.
+    ///
+    /// **NOTE:** Returns [`None`] if `index` is past the last digit.
+    pub const fn digit_at(&self, index: u32) -> Option<u8> {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if index >= length {
+            return None;
+        }
+
+        // The digits are symmetric, so counting from the least significant digit is the same.
+        Some((self.0 / 10u64.pow(index) % 10) as u8)
+    }
+
+    /// Return the base 10 logarithm of [`Self`], rounded down. E.g.: `12321` returns `Some(4)`.
+    ///
+    /// **NOTE:** Returns [`None`] for `0`. This differs from the number of digits minus one,
+    /// which treats `0` as a single digit just like `1` through `9` (see
+    /// [`PalindromeIter::digit_length_histogram`]), so `0` can be told apart from them.
+    pub const fn ilog10(&self) -> Option<u32> {
+        self.0.checked_ilog10()
+    }
+
+    /// Return the sum of the digits of [`Self`]. E.g.: `12321` returns `9`.
+    pub const fn digit_sum(&self) -> u32 {
+        // The second half mirrors the first, so only the first half is summed.
+        let sum = self.half_digit_sum() * 2;
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if length % 2 == 1 {
+            // Center digit only appears once.
+            let center = self.0 / 10u64.pow(length / 2) % 10;
+            return sum - center as u32;
+        }
+
+        sum
+    }
+
+    /// Return the sum of the first half of the digits of [`Self`], including the center digit.
+    /// E.g.: `12321` returns `6` (`1 + 2 + 3`).
+    pub const fn half_digit_sum(&self) -> u32 {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        let mut first_half = self.0 / 10u64.pow(length / 2);
+        let mut sum = 0;
+        while first_half > 0 {
+            sum += (first_half % 10) as u32;
+            first_half /= 10;
+        }
+
+        sum
+    }
+
+    /// Return an ascending iterator over every palindrome below `max` whose digit sum
+    /// (see [`Self::digit_sum`]) is `sum`. E.g.: `sum=4` and `max=10000` yields `4`, `22`,
+    /// `121`, `202`, `1111` and `2002`.
+    ///
+    /// **NOTE:** Only the first halves with a matching sum are generated, where every digit
+    /// counts twice except the center digit. So palindromes with another digit sum are never
+    /// visited, except to find the next matching first half.
+    pub fn with_digit_sum(sum: u32, max: u64) -> impl Iterator<Item = Self> {
+        let mut length = 1;
+        let mut half: Option<Vec<u8>> = None;
+        std::iter::from_fn(move || loop {
+            if length > Self::MAX_LEN {
+                return None;
+            }
+            let found = match half.as_mut() {
+                Some(half) => Self::next_half_with_sum(half, length, sum),
+                None => {
+                    let first_half = half.insert(vec![0; length.div_ceil(2)]);
+                    let lowest_lead = if length == 1 { 0 } else { 1 };
+                    (lowest_lead..=9).any(|lead| {
+                        first_half[0] = lead;
+                        match sum.checked_sub(Self::half_digit_weight(0, length) * lead as u32) {
+                            Some(rest) => Self::fill_half_with_sum(first_half, length, 1, rest),
+                            None => false,
+                        }
+                    })
+                }
+            };
+            if !found {
+                length += 1;
+                half = None;
+                continue;
+            }
+
+            let seed = half
+                .iter()
+                .flatten()
+                .fold(0, |seed, &digit| seed * 10 + digit as u64);
+            match Self::construct(&Self::to_digits(seed), length) {
+                Some(palindrome) if palindrome.0 < max => return Some(palindrome),
+                _ => length = Self::MAX_LEN + 1, // Every later palindrome is too large as well.
+            }
+        })
+    }
+
+    /// Return how often digit `idx` of the first half appears in a palindrome with `length`
+    /// digits, i.e. `1` for the center digit and `2` for every other digit.
+    const fn half_digit_weight(idx: usize, length: usize) -> u32 {
+        if length % 2 == 1 && idx == length / 2 {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Fill `half[from..]` with the smallest digits that add `rest` to the digit sum,
+    /// and return whether that's possible.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn fill_half_with_sum(half: &mut [u8], length: usize, from: usize, mut rest: u32) -> bool {
+        // The later digits get as much as possible, so the earlier digits stay small.
+        for idx in (from..half.len()).rev() {
+            let weight = Self::half_digit_weight(idx, length);
+            let mut digit = (rest / weight).min(9);
+            if weight == 1 && idx > from && (rest - digit) % 2 != 0 {
+                // Only the center digit can leave an odd rest, which the other digits can't fill.
+                digit -= 1;
+            }
+            half[idx] = digit as u8;
+            rest -= digit * weight;
+        }
+
+        rest == 0
+    }
+
+    /// Advance `half` to the next larger first half with the same digit sum,
+    /// and return whether there is one.
+    fn next_half_with_sum(half: &mut [u8], length: usize, sum: u32) -> bool {
+        for idx in (0..half.len()).rev() {
+            let prefix: u32 = (0..idx)
+                .map(|i| Self::half_digit_weight(i, length) * half[i] as u32)
+                .sum();
+            for digit in half[idx] + 1..=9 {
+                let used = prefix + Self::half_digit_weight(idx, length) * digit as u32;
+                if used > sum {
+                    break;
+                }
+                half[idx] = digit;
+                if Self::fill_half_with_sum(half, length, idx + 1, sum - used) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Return the digital root of [`Self`], i.e. the digit sum repeated until a single digit remains.
+    /// E.g.: `98789` returns `5` (`98789` -> `41` -> `5`).
+    pub const fn digital_root(&self) -> u8 {
+        if self.0 == 0 {
+            return 0;
+        }
+
+        (1 + (self.0 - 1) % 9) as u8
+    }
+
+    /// Return the palindrome closest to the geometric mean of `a` and `b`, i.e. `sqrt(a * b)`.
+    ///
+    /// **NOTE:** The geometric mean is rounded down before finding the closest palindrome.
+    pub const fn geometric_closest(a: Self, b: Self) -> Self {
+        let mean = Self::isqrt(a.0 as u128 * b.0 as u128);
+        Self::closest(mean as u64)
+    }
+
+    /// Construct a palindrome from the first half of a digit and a provided length.
+    ///
+    /// Returns [`None`] if the palindrome is larger than [`Self::MAX`].
+    ///
+    /// NOTE: Will panic if `length` isn't `2x` or `2x - 1` the size of `digits_half.len()`.
+    const fn construct(digits_half: &PalindromeDigits, length: usize) -> Option<Self> {
+        // If we have a 5-digit number, then we construct by using
+        // the 1st, 2nd, 3rd, 2nd, and 1st elements.
+        // If we have a 6-digit number, then we construct by using
+        // the 1st, 2nd, 3rd, 3rd, 2nd, and 1st elements.
+        let second_half_length = length - digits_half.len();
+        let mut palindrome = 0;
+        let mut idx = 0; // first half idx
+        while idx < digits_half.len() {
+            palindrome *= 10;
+            palindrome += digits_half.get(idx) as u64;
+            idx += 1;
+        }
+        idx = 1; // second half reverse idx
+        while idx <= second_half_length {
+            // Only 20-digit palindromes can overflow, and only in the second half.
+            palindrome = match palindrome.checked_mul(10) {
+                Some(x) => match x.checked_add(digits_half.get(second_half_length - idx) as u64) {
+                    Some(x) => x,
+                    None => return None,
+                },
+                None => return None,
+            };
+            idx += 1;
+        }
+
+        Some(Palindrome(palindrome))
+    }
+
+    /// Return the palindrome made of `digits`, ordered from the most to the least significant.
+    /// E.g.: `[1, 2, 1]` returns `121`.
+    pub const fn try_from_digits(digits: &[u8]) -> Result<Self, PalindromeError> {
+        if digits.is_empty() {
+            return Err(PalindromeError::Empty);
+        }
+
+        let mut idx = 0;
+        while idx < digits.len() {
+            if digits[idx] > 9 {
+                return Err(PalindromeError::InvalidDigit(digits[idx]));
+            }
+            idx += 1;
+        }
+        if digits[0] == 0 && digits.len() > 1 {
+            return Err(PalindromeError::LeadingZero);
+        }
+
+        let (mut i, mut j) = (0, digits.len() - 1);
+        while i < j {
+            if digits[i] != digits[j] {
+                return Err(PalindromeError::NotPalindrome);
+            }
+            i += 1;
+            j -= 1;
+        }
+
+        let mut palindrome: u64 = 0;
+        idx = 0;
+        while idx < digits.len() {
+            palindrome = match palindrome.checked_mul(10) {
+                Some(x) => match x.checked_add(digits[idx] as u64) {
+                    Some(x) => x,
+                    None => return Err(PalindromeError::Overflow),
+                },
+                None => return Err(PalindromeError::Overflow),
+            };
+            idx += 1;
+        }
+
+        Ok(Palindrome(palindrome))
+    }
+
+    /// Return [`Self`] in decimal with `sep` between every group of three digits.
+    /// E.g.: `1234321` with `sep=','` returns `"1,234,321"`.
+    ///
+    /// **NOTE:** The groups are counted from the right, so the result is usually
+    /// no longer a palindrome itself (`"12,321"` reversed is `"123,21"`).
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn to_grouped_string(&self, sep: char) -> String {
+        let digits = self.0.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * sep.len_utf8());
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx) % 3 == 0 {
+                grouped.push(sep);
+            }
+            grouped.push(digit);
+        }
+
+        grouped
+    }
+
+    /// Return [`Self`] in decimal with `sep` at the mirror point, on both sides of the
+    /// center digit if there is one. E.g.: `123321` with `sep='|'` returns `"123|321"`,
+    /// and `12321` returns `"12|3|21"`.
+    ///
+    /// **NOTE:** A single digit is its own center, so `5` returns `"|5|"`.
+    pub fn format_mirrored(&self, sep: char) -> String {
+        let digits = self.0.to_string();
+        let half = digits.len() / 2;
+        let mut mirrored = String::with_capacity(digits.len() + 2 * sep.len_utf8());
+        mirrored.push_str(&digits[..half]);
+        mirrored.push(sep);
+        if digits.len() % 2 == 1 {
+            mirrored.push_str(&digits[half..half + 1]);
+            mirrored.push(sep);
+        }
+        mirrored.push_str(&digits[digits.len() - half..]);
+
+        mirrored
+    }
+
+    /// Return [`Self`] rendered by `f`, for representations other than decimal.
+    /// E.g.: `5` with a formatter for Roman numerals returns `"V"`.
+    ///
+    /// **NOTE:** `f` receives the value of [`Self`]. Use [`Display`] for plain decimal.
+    pub fn render_with<F: Fn(u64) -> String>(&self, f: F) -> String {
+        f(self.0)
+    }
+
+    /// Construct a palindrome like [`Self::construct`], but return [`Self::MAX`]
+    /// if the palindrome would be larger.
+    const fn construct_saturating(digits_half: &PalindromeDigits, length: usize) -> Self {
+        match Self::construct(digits_half, length) {
+            Some(p) => p,
+            None => Self::MAX,
+        }
+    }
+
+    /// Return a PalindromeDigits.
+    /// NOTE: Wasting stack since arrays can't have dynamic length.
+    const fn to_digits(mut x: u64) -> PalindromeDigits {
+        let length = match x.checked_ilog10() {
+            Some(x) => x as usize + 1,
+            None => 1,
+        };
+        let mut arr: [u8; Self::MAX_LEN] = [0; Self::MAX_LEN];
+
+        let mut idx = 1;
+        while idx <= length {
+            arr[Self::MAX_LEN - idx] = (x % 10) as u8;
+            x /= 10;
+            idx += 1;
+        }
+
+        PalindromeDigits::from(arr, length)
+    }
+
+    /// Return the largest palindrome with `n` digits. E.g.: `n=3` returns `999`.
+    ///
+    /// **ATTENTION:** `n=0` returns [`Self::MIN`] and any `n` above `20` returns [`Self::MAX`].
+    pub const fn largest_with_digits(n: u32) -> Self {
+        if n == 0 {
+            return Self::MIN;
+        }
+        if n >= Self::MAX_LEN as u32 {
+            return Self::MAX;
+        }
+
+        Palindrome(10u64.pow(n) - 1)
+    }
+
+    /// Return the largest palindrome that fits in an unsigned integer with `bits` bits,
+    /// i.e. the largest palindrome less than or equal to `2^bits - 1`.
+    /// E.g.: `bits=8` returns `252` and `bits=64` returns [`Self::MAX`].
+    ///
+    /// **NOTE:** Returns [`None`] if `bits` is above `64`. `bits=0` returns [`Self::MIN`].
+    pub const fn max_for_bits(bits: u32) -> Option<Self> {
+        if bits > u64::BITS {
+            return None;
+        }
+        if bits == 0 {
+            return Some(Self::MIN);
+        }
+
+        Some(Self::le(u64::MAX >> (u64::BITS - bits)))
+    }
+
+    /// Return the palindrome with exactly `n` digits that is closest to `x`.
+    /// E.g.: `x=500` and `n=2` returns `99`, and `x=5` and `n=3` returns `101`.
+    ///
+    /// **NOTE:** Ties are resolved like [`Self::closest`].
+    ///
+    /// **ATTENTION:** `n=0` is treated as `n=1` and any `n` above `20` is treated as `n=20`.
+    pub const fn closest_with_length(x: u64, n: u32) -> Self {
+        let n = if n == 0 {
+            1
+        } else if n > Self::MAX_LEN as u32 {
+            Self::MAX_LEN as u32
+        } else {
+            n
+        };
+
+        let smallest = if n == 1 { 0 } else { 10u64.pow(n - 1) + 1 };
+        let largest = Self::largest_with_digits(n);
+        if x <= smallest {
+            Palindrome(smallest)
+        } else if x >= largest.0 {
+            largest
+        } else {
+            // Both neighbouring palindromes of `x` have `n` digits as well.
+            Self::closest(x)
+        }
+    }
+
+    /// Return the nth palindrome (0-based indexing).
+    ///
+    /// **NOTE:** Returns [`None`] if the palindrome is larger than [`Self::MAX`].
+    pub const fn nth(n: usize) -> Option<Self> {
+        if n > Self::MAX_N {
+            return None;
+        }
+
+        // 10th number (9 on 0-based indexing) is an edge case.
+        if n < 10 {
+            return Some(Self(n as u64));
+        }
+
+        let mut n_copy = n;
+        let mut n_digits = 1;
+        while n_digits <= Self::MAX_N {
+            // n_digits = 2
+            if n_copy < PalindromeIter::palindromes_in_n_digits(n_digits as u8) {
+                // Remove the palindromes below n-digit palindromes.
+                n_copy -= PalindromeIter::palindromes_in_n_digits(n_digits as u8 - 1);
+                let first_n_digits = n_digits.div_ceil(2);
+                let first_half = 10u64.pow(first_n_digits as u32 - 1) + n_copy as u64;
+                let digits_half = Self::to_digits(first_half);
+
+                return Self::construct(&digits_half, n_digits);
+            }
+            n_digits += 1;
+        }
+
+        None
+    }
+
+    /// Return the nth palindrome (0-based indexing), like [`Self::nth`].
+    ///
+    /// **ATTENTION:** Any `n` above the index of [`Self::MAX`] will return [`Self::MAX`].
+    pub const fn nth_saturating(n: usize) -> Self {
+        match Self::nth(n) {
+            Some(p) => p,
+            None => Self::MAX,
+        }
+    }
+
+    /// Return the index (see [`Self::nth`]) of the first palindrome for which `pred` returns `false`,
+    /// or the amount of palindromes if `pred` is always `true`.
+    ///
+    /// **NOTE:** `pred` must be monotonic, i.e. return `true` for every palindrome before the
+    /// returned index and `false` for every palindrome after it. Otherwise the result is
+    /// meaningless, just like [`slice::partition_point`].
+    pub fn partition_point<F: Fn(Self) -> bool>(pred: F) -> usize {
+        let (mut low, mut high) = (0, Self::MAX_N + 1);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match Self::nth(mid) {
+                Some(p) if pred(p) => low = mid + 1,
+                _ => high = mid,
+            }
+        }
+
+        low
+    }
+
+    /// Return an iterator over every palindrome from [`Self::MAX`] down to [`Self::MIN`].
+    /// E.g.: `Palindrome::all_descending().take(100)` yields the 100 largest palindromes.
+    pub fn all_descending() -> impl Iterator<Item = Self> {
+        PalindromeIter::from(Self::MIN, Self::MAX.0 + 1).rev()
+    }
+
+    /// Return an iterator over the palindromes grouped by their amount of digits, where every
+    /// [`PalindromeIter`] covers exactly one length, from `1` up to and including `max_length`.
+    /// E.g.: `max_length=3` yields the ranges `0..10`, `10..100` and `100..1000`.
+    ///
+    /// **NOTE:** Any `max_length` above `20` is treated as `20`.
+    pub fn by_length(max_length: u32) -> impl Iterator<Item = PalindromeIter> {
+        (1..=max_length.min(Self::MAX_LEN as u32)).map(|length| {
+            let lowest = if length == 1 {
+                0
+            } else {
+                10u64.pow(length - 1)
+            };
+            let highest = match 10u64.checked_pow(length) {
+                Some(highest) => highest,
+                None => Self::MAX.0 + 1, // Include MAX.
+            };
+            PalindromeIter::from(lowest, highest)
+        })
+    }
+
+    /// Return the nth palindrome (0-based indexing) with exactly `length` digits.
+    /// E.g.: `n=2` and `length=4` returns `1221` (after `1001` and `1111`).
+    ///
+    /// **NOTE:** Returns [`None`] if there are `n` or fewer palindromes with `length` digits,
+    /// or if the palindrome is larger than [`Self::MAX`].
+    pub const fn nth_with_length(n: usize, length: u32) -> Option<Self> {
+        let (low, high) = Self::seed_range(length);
+        if n as u64 >= high - low {
+            return None;
+        }
+
+        Self::construct(&Self::to_digits(low + n as u64), length as usize)
+    }
+
+    /// Return the range `low..high` of first halves (seeds) that make every palindrome with
+    /// exactly `length` digits. E.g.: `length=4` returns `(10, 100)`, since the seeds `10`
+    /// to `99` make the palindromes `1001` to `9999`.
+    ///
+    /// **NOTE:** A seed has `ceil(length / 2)` digits, and for an odd `length` its last digit
+    /// is the center digit. `length=1` returns `(0, 10)`, since `0` is a palindrome.
+    /// For `length=20` the range ends after the seed of [`Self::MAX`].
+    /// The range is empty if `length` is `0` or above the length of [`Self::MAX`].
+    pub const fn seed_range(length: u32) -> (u64, u64) {
+        if length == 0 || length > Self::MAX_LEN as u32 {
+            return (0, 0);
+        }
+        if length == 1 {
+            return (0, 10);
+        }
+
+        let half_length = length.div_ceil(2);
+        let low = 10u64.pow(half_length - 1);
+        if length == Self::MAX_LEN as u32 {
+            return (low, Self::MAX.0 / 10u64.pow(half_length) + 1);
+        }
+
+        (low, low * 10)
+    }
+
+    /// Return the `n` value of [`Self`].
+    ///
+    /// Opposite of [`Self::nth`].
+    pub const fn to_n(&self) -> usize {
+        PalindromeIter::len_from_0(self.0)
+    }
+
+    /// Return the previous palindromic number.
+    ///
+    /// **NOTE:** Lowest return-value is [`Self::MIN`].
+    pub const fn previous(&self) -> Self {
+        Self::le(self.0.saturating_sub(1))
+    }
+
+    /// Return the next palindromic number.
+    ///
+    /// **NOTE:** Highest return-value is [`Self::MAX`].
+    pub const fn next(&self) -> Self {
+        Self::ge(self.0 + 1)
+    }
+
+    /// Return the next palindromic number, like [`Self::next`], by incrementing the first half
+    /// of [`Self`] and mirroring it. E.g.: `12321` (first half `123`) returns `12421`,
+    /// and `999` (first half `99`) returns `1001`.
+    ///
+    /// **NOTE:** Highest return-value is [`Self::MAX`].
+    pub const fn next_via_half(&self) -> Self {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        let half_length = length.div_ceil(2);
+        let half = self.0 / 10u64.pow(length - half_length) + 1;
+        if half == 10u64.pow(half_length) {
+            // Every digit was a 9, so the next palindrome has one more digit.
+            if length == Self::MAX_LEN as u32 {
+                return Self::MAX;
+            }
+            return Palindrome(10u64.pow(length) + 1);
+        }
+
+        Self::construct_saturating(&Self::to_digits(half), length as usize)
+    }
+
+    /// Return the next palindromic number, or [`None`] if [`Self`] is [`Self::MAX`].
+    ///
+    /// **NOTE:** Unlike [`Self::next`], this never clamps. [`Self::MAX`] is the largest
+    /// palindrome that fits in a [`u64`], so it's returned for the palindrome just below it,
+    /// and there's nothing after it.
+    pub const fn checked_next(&self) -> Option<Self> {
+        if self.0 >= Self::MAX.0 {
+            return None;
+        }
+
+        Some(self.next_via_half())
+    }
+
+    /// Return the first palindrome whose value is at least `delta` above [`Self`].
+    /// E.g.: `121` with `delta=100` returns `222`.
+    ///
+    /// **NOTE:** `delta` is a difference in value, not in palindromes.
+    /// Use [`Self::try_next_n`] to skip a number of palindromes instead.
+    ///
+    /// **ATTENTION:** Highest return-value is [`Self::MAX`].
+    pub const fn next_at_least(&self, delta: u64) -> Self {
+        Self::ge(self.0.saturating_add(delta))
+    }
+
+    /// Return the smallest palindrome with one more digit than [`Self`].
+    /// E.g.: any 3-digit palindrome returns `1001`.
+    ///
+    /// **NOTE:** Returns [`None`] if [`Self`] already has as many digits as [`Self::MAX`].
+    pub const fn next_length(&self) -> Option<Self> {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if length >= Self::MAX_LEN as u32 {
+            return None;
+        }
+
+        Some(Palindrome(10u64.pow(length) + 1))
+    }
+
+    /// Return the largest palindrome with one digit less than [`Self`].
+    /// E.g.: any 3-digit palindrome returns `99`.
+    ///
+    /// **NOTE:** Returns [`None`] if [`Self`] only has a single digit.
+    pub const fn previous_length(&self) -> Option<Self> {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        if length == 1 {
+            return None;
+        }
+
+        Some(Self::largest_with_digits(length - 1))
+    }
+
+    /// Return an iterator over progressively smaller palindromes, for narrowing down
+    /// a failing case. Every step halves the palindrome-index, ending at [`Self::MIN`].
+    /// E.g.: `1001` (index 109) yields `454` (index 54), `181` (index 27), `44`, `6`, `3`, `1`
+    /// and `0`.
+    ///
+    /// **NOTE:** The largest palindrome is yielded first. [`Self::MIN`] yields nothing.
+    pub fn shrink(&self) -> impl Iterator<Item = Self> {
+        let mut n = self.to_n();
+        std::iter::from_fn(move || {
+            if n == 0 {
+                return None;
+            }
+
+            n /= 2;
+            Self::nth(n)
+        })
+    }
+
+    /// Return the palindrome closest to [`Self`] scaled by `num / den`.
+    /// E.g.: `12321` scaled by `3 / 2` returns `18481` (`12321 * 3 / 2` rounded down).
+    ///
+    /// **NOTE:** Returns [`None`] if `den` is `0`. Any scaled value above [`Self::MAX`]
+    /// returns [`Self::MAX`].
+    pub const fn scaled(&self, num: u64, den: u64) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let x = self.0 as u128 * num as u128 / den as u128;
+        if x >= Self::MAX.0 as u128 {
+            return Some(Self::MAX);
+        }
+
+        Some(Self::closest(x as u64))
+    }
+
+    /// Return the palindrome closest to [`Self`] multiplied by `rhs`.
+    /// E.g.: `121` multiplied by `3` returns `363`, and multiplied by `4` returns `484`.
+    ///
+    /// **NOTE:** Returns [`None`] if the product overflows [`u64`]. Any product above
+    /// [`Self::MAX`] that fits in [`u64`] returns [`Self::MAX`].
+    pub const fn mul_snap(&self, rhs: u64) -> Option<Self> {
+        let product = self.0 as u128 * rhs as u128;
+        if product > u64::MAX as u128 {
+            return None;
+        }
+
+        Some(Self::closest(product as u64))
+    }
+
+    /// Return the palindrome closest to the sum of the values of [`Self`] and `other`.
+    /// E.g.: `121` plus `99` is `220`, so `222` is returned.
+    ///
+    /// **NOTE:** This adds values, not palindrome-indices. To move a number of palindromes
+    /// forward, use [`Self::offset`] or [`Self::checked_next_n`] instead.
+    /// Returns [`None`] if the sum overflows [`u64`]. Any sum above [`Self::MAX`] that fits
+    /// in [`u64`] returns [`Self::MAX`].
+    pub const fn add_snap(&self, other: &Palindrome) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(sum) => Some(Self::closest(sum)),
+            None => None,
+        }
+    }
+
+    /// Return [`Self`] divided by `divisor` if the division is exact and the quotient is
+    /// a palindrome. E.g.: `484` divided by `4` returns `121`, but `252` divided by `2`
+    /// returns [`None`], since `126` isn't a palindrome.
+    ///
+    /// **NOTE:** Returns [`None`] if there's a remainder, if the quotient isn't a palindrome
+    /// or if `divisor` is `0`.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn div_exact_palindrome(&self, divisor: &Palindrome) -> Option<Self> {
+        if divisor.0 == 0 || self.0 % divisor.0 != 0 {
+            return None;
+        }
+
+        let quotient = self.0 / divisor.0;
+        if Self::is_palindrome(quotient) {
+            return Some(Self(quotient));
+        }
+
+        None
+    }
+
+    /// Return every pair of factors of [`Self`] whose first factor is a palindrome,
+    /// ordered by the first factor. E.g.: `121` returns `[(1, 121), (11, 11), (121, 1)]`.
+    ///
+    /// **NOTE:** A pair where both factors are palindromes appears in both orders,
+    /// and `0` returns an empty [`Vec`]. This function finds the factors by trial division
+    /// up to the square root of [`Self`], so it can be slow for large palindromes.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn palindrome_factors(&self) -> Vec<(Self, u64)> {
+        let x = self.0;
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        let mut d = 1;
+        while d <= x / d {
+            if x % d == 0 {
+                let other = x / d;
+                if Self::is_palindrome(d) {
+                    small.push((Self(d), other));
+                }
+                if other != d && Self::is_palindrome(other) {
+                    large.push((Self(other), d));
+                }
+            }
+            d += 1;
+        }
+        small.extend(large.into_iter().rev());
+
+        small
+    }
+
+    /// Return whether [`Self`] is a Smith number, i.e. a composite number whose digit sum
+    /// equals the sum of the digit sums of its prime factors (with multiplicity).
+    /// E.g.: `202` is `2 * 101`, and `2 + 0 + 2 == 2 + (1 + 0 + 1)`, so it's a Smith number.
+    ///
+    /// **NOTE:** Primes, `0` and `1` are never Smith numbers.
+    ///
+    /// **ATTENTION:** Factorizes by trial division, so this is slow for palindromes with two or
+    /// more large prime factors.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn is_smith(&self) -> bool {
+        let x = self.0;
+        if x < 4 || Self::is_prime(x) {
+            return false;
+        }
+
+        let mut factor_sum = 0;
+        let mut rest = x;
+        let mut d = 2;
+        while d <= rest / d {
+            if rest % d == 0 {
+                while rest % d == 0 {
+                    factor_sum += Self::digit_sum_of(d);
+                    rest /= d;
+                }
+                if Self::is_prime(rest) {
+                    break; // The rest is the last prime factor.
+                }
+            }
+            d += 1;
+        }
+        if rest > 1 {
+            factor_sum += Self::digit_sum_of(rest);
+        }
+
+        factor_sum == self.digit_sum()
+    }
+
+    /// Return the sum of the digits of `x`, which doesn't have to be a palindrome.
+    const fn digit_sum_of(mut x: u64) -> u32 {
+        let mut sum = 0;
+        while x > 0 {
+            sum += (x % 10) as u32;
+            x /= 10;
+        }
+
+        sum
+    }
+
+    /// Return every way to split the digits of [`Self`] into two non-empty parts that are both
+    /// palindromes, as `(left, right)` ordered by the length of `left`.
+    /// E.g.: `1111` returns `[(1, 111), (11, 11), (111, 1)]`.
+    ///
+    /// **NOTE:** A part with a leading zero is never a palindrome, even if its value is,
+    /// so `1001` can't be split into `1` and `001`.
+    pub fn splits_into_palindromes(&self) -> Vec<(u64, u64)> {
+        let length = match self.0.checked_ilog10() {
+            Some(exp) => exp + 1,
+            None => 1,
+        };
+        let mut splits = Vec::new();
+        for right_length in (1..length).rev() {
+            let unit = 10u64.pow(right_length);
+            let (left, right) = (self.0 / unit, self.0 % unit);
+            if right < unit / 10 {
+                continue; // Leading zero.
+            }
+            if Self::is_palindrome(left) && Self::is_palindrome(right) {
+                splits.push((left, right));
+            }
+        }
+
+        splits
+    }
+
+    /// Return two smaller, non-zero palindromes that sum to [`Self`], or [`None`] if there are none.
+    /// E.g.: `121` returns `(22, 99)`.
+    ///
+    /// **NOTE:** The pair with the smallest first palindrome is returned, and the first
+    /// palindrome is never larger than the second. This function checks every candidate
+    /// up to half of [`Self`] one by one, so it can be slow for large palindromes without a pair.
+    pub fn as_palindrome_sum(&self) -> Option<(Self, Self)> {
+        PalindromeIter::from(1u64, self.0 / 2 + 1)
+            .find(|smaller| Self::is_palindrome(self.0 - smaller.0))
+            .map(|smaller| (smaller, Self(self.0 - smaller.0)))
+    }
+
+    /// Return the numbers below `limit` that reach [`Self`] in one reverse-and-add step.
+    /// E.g.: `121` is reached from `29` (`29 + 92`), `38`, `47`, `56`, `65`, `74`, `83` and `92`.
+    ///
+    /// **NOTE:** The predecessors are found digit pair by digit pair instead of checking
+    /// every number below `limit`, so this takes time proportional to the amount of predecessors
+    /// below [`u64::MAX`], whatever `limit` is. That's a single one for `1000000001`, but can
+    /// be millions for large palindromes: [`Self::MAX`] has `2205000`.
+    pub fn reverse_add_predecessors(&self, limit: u64) -> Vec<u64> {
+        let length = match self.0.checked_ilog10() {
+            Some(x) => x + 1,
+            None => 1,
+        };
+
+        // An n-digit number reverse-added is an n- or (n + 1)-digit number.
+        let mut predecessors = Vec::new();
+        let mut sums = [0; 10];
+        for width in length.saturating_sub(1).max(1)..=length {
+            Self::push_reverse_add_predecessors(self.0, width, 0, 0, &mut sums, &mut predecessors);
+        }
+
+        predecessors.retain(|&x| x < limit);
+        predecessors.sort_unstable();
+        predecessors
+    }
+
+    // In a `width`-digit `x`, digit `i` and digit `width - 1 - i` add up to `sums[i]` in
+    // `x + reverse(x)`. Going from the lowest digit of `target` inwards, each of these sums
+    // is one of two values, depending on the carry. Every combination of sums that adds up
+    // to `target` is expanded into the numbers with those digit pairs and pushed to `out`.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn push_reverse_add_predecessors(
+        target: u64,
+        width: u32,
+        pair: u32,
+        carry: u8,
+        sums: &mut [u8; 10],
+        out: &mut Vec<u64>,
+    ) {
+        let pairs = width / 2;
+        let digit = (target / 10u64.pow(pair) % 10) as u8;
+        let low = (digit + 10 - carry) % 10;
+        if pair < pairs {
+            for sum in [low, low + 10] {
+                if sum <= 18 {
+                    sums[pair as usize] = sum;
+                    let carry = (sum + carry) / 10;
+                    Self::push_reverse_add_predecessors(target, width, pair + 1, carry, sums, out);
+                }
+            }
+            return;
+        }
+
+        // The middle digit of an odd width is added to itself.
+        let middles: &[u8] = if width % 2 == 0 {
+            &[0]
+        } else if low % 2 == 0 {
+            &[low / 2, low / 2 + 5]
+        } else {
+            &[]
+        };
+        for &middle in middles {
+            let mut total = 2 * middle as u128 * 10u128.pow(pairs);
+            for i in 0..pairs {
+                total += sums[i as usize] as u128 * (10u128.pow(i) + 10u128.pow(width - 1 - i));
+            }
+            if total != target as u128 {
+                continue;
+            }
+
+            let mut numbers = vec![middle as u64 * 10u64.pow(pairs)];
+            for i in 0..pairs {
+                let sum = sums[i as usize];
+                let mut next = Vec::new();
+                for low_digit in sum.saturating_sub(9)..=sum.min(9) {
+                    let high_digit = sum - low_digit;
+                    // The leading digit can't be `0`.
+                    if i == 0 && high_digit == 0 {
+                        continue;
+                    }
+                    let digits = low_digit as u64 * 10u64.pow(i)
+                        + high_digit as u64 * 10u64.pow(width - 1 - i);
+                    next.extend(numbers.iter().map(|x| x + digits));
+                }
+                numbers = next;
+            }
+            out.extend(numbers);
+        }
+    }
+
+    /// Return the result of repeatedly adding the reverse of a number to itself, starting at
+    /// `start`, for at most `max_iters` steps. E.g.: `56` reaches `121` after `1` step (`56 + 65`),
+    /// while `196` never seems to reach a palindrome.
+    ///
+    /// **NOTE:** At least one step is always taken, even if `start` is a palindrome,
+    /// like in the definition of Lychrel numbers.
+    pub const fn classify_reverse_add(start: u64, max_iters: usize) -> ReverseAddResult {
+        let mut x = start;
+        let mut step = 1;
+        while step <= max_iters {
+            let sum = x as u128 + Self::reverse(x);
+            if sum > u64::MAX as u128 {
+                return ReverseAddResult::Overflowed(x);
+            }
+            x = sum as u64;
+            if Self::is_palindrome(x) {
+                return ReverseAddResult::Palindrome(Palindrome(x), step);
+            }
+            step += 1;
+        }
+
+        ReverseAddResult::Suspected
+    }
+
+    /// Return whether [`Self`] equals the digits of `other` in reverse order.
+    /// E.g.: `121` is the reverse of `121`, and `1221` is the reverse of `12210`.
+    ///
+    /// **NOTE:** Trailing zeros of `other` become leading zeros in the reverse, which are dropped.
+    /// So [`Self`] is the reverse of itself followed by any amount of zeros, and nothing else.
+    pub const fn is_reverse_of(&self, other: u64) -> bool {
+        self.0 as u128 == Self::reverse(other)
+    }
+
+    /// Return the digits of `x` in reverse order. E.g.: `1230` returns `321`.
+    const fn reverse(mut x: u64) -> u128 {
+        let mut reversed = 0;
+        while x > 0 {
+            reversed = reversed * 10 + (x % 10) as u128;
+            x /= 10;
+        }
+
+        reversed
+    }
+
+    /// Return the amount of palindromic primes below `x`.
+    /// E.g.: `x=200` returns `10` (`2`, `3`, `5`, `7`, `11`, `101`, `131`, `151`, `181` and `191`).
+    ///
+    /// **NOTE:** This function is O(number of palindromes below `x`) rather than O(`x`),
+    /// since only palindromes are tested for primality (see [`Self::primes_in_range`]).
+    pub fn count_primes_below(x: u64) -> usize {
+        Self::primes_in_range(0, x).count()
+    }
+
+    /// Return an iterator over the palindromic primes in `from..to`, in ascending order.
+    /// E.g.: `from=10` and `to=200` yields `11`, `101`, `131`, `151`, `181` and `191`.
+    ///
+    /// **NOTE:** Palindromes with an even amount of digits are divisible by `11`, and palindromes
+    /// starting with an even digit or `5` end with one, so those are skipped entirely instead of
+    /// being tested. `11` itself is the only palindromic prime with an even amount of digits.
+    pub fn primes_in_range(from: u64, to: u64) -> impl Iterator<Item = Self> {
+        let small = [2, 3, 5, 7, 11]
+            .into_iter()
+            .filter(move |&p| from <= p && p < to)
+            .map(Self);
+
+        let bands = (3..=Self::MAX_LEN as u32).step_by(2).flat_map(|length| {
+            let magnitude = 10u64.pow(length - 1);
+            // `(first_digit + 1) * magnitude` only overflows for 20 digits, which are even.
+            [1, 3, 7, 9].map(|first_digit| (first_digit * magnitude, (first_digit + 1) * magnitude))
+        });
+        let large = bands
+            .take_while(move |&(lowest, _)| lowest < to)
+            .filter(move |&(_, highest)| highest > from)
+            .flat_map(move |(lowest, highest)| {
+                PalindromeIter::from(lowest.max(from), highest.min(to))
+            })
+            .filter(|palindrome| Self::is_prime(palindrome.0));
+
+        small.chain(large)
+    }
+
+    /// Return the palindromic prime closest to `x`. E.g.: `x=200` returns `191`.
+    ///
+    /// **NOTE:** Ties are resolved like [`Self::closest`]. Since `2` is a palindromic prime,
+    /// every `x` has a closest palindromic prime. Palindromes with an even amount of digits
+    /// (except `11`) or starting with an even digit or `5` are skipped without testing them.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn closest_prime(x: u64) -> Self {
+        let mut below = if x < 2 { None } else { Some(Self::le(x)) };
+        while let Some(palindrome) = below {
+            let length = match palindrome.0.checked_ilog10() {
+                Some(exp) => exp + 1,
+                None => 1,
+            };
+            let magnitude = 10u64.pow(length - 1);
+            let first_digit = palindrome.0 / magnitude;
+            below = if length % 2 == 0 && palindrome.0 != 11 {
+                // Jump to the largest palindrome with one digit less.
+                if length == 2 {
+                    Some(Self(11))
+                } else {
+                    Some(Self::le(magnitude - 1))
+                }
+            } else if length > 1 && (first_digit % 2 == 0 || first_digit == 5) {
+                // Jump to the largest palindrome with a lower first digit.
+                Some(Self::le(first_digit * magnitude - 1))
+            } else if Self::is_prime(palindrome.0) {
+                break;
+            } else if palindrome.0 < 2 {
+                None
+            } else {
+                Some(palindrome.previous())
+            };
+        }
+
+        let mut above = if x > Self::MAX.0 {
+            None
+        } else {
+            Some(Self::ge(x))
+        };
+        while let Some(palindrome) = above {
+            let length = match palindrome.0.checked_ilog10() {
+                Some(exp) => exp + 1,
+                None => 1,
+            };
+            let magnitude = 10u64.pow(length - 1);
+            let first_digit = palindrome.0 / magnitude;
+            above = if length % 2 == 0 && palindrome.0 != 11 {
+                // Jump to the smallest palindrome with one digit more.
+                match magnitude.checked_mul(10) {
+                    Some(block) if block <= Self::MAX.0 => Some(Self::ge(block)),
+                    _ => None,
+                }
+            } else if length > 1 && (first_digit % 2 == 0 || first_digit == 5) {
+                // Jump to the smallest palindrome with a higher first digit.
+                Some(Self::ge((first_digit + 1) * magnitude))
+            } else if Self::is_prime(palindrome.0) {
+                break;
+            } else if palindrome.0 == Self::MAX.0 {
+                None
+            } else {
+                Some(palindrome.next())
+            };
+        }
+
+        match (below, above) {
+            (Some(b), Some(a)) => {
+                if a.0 - x <= x - b.0 {
+                    a
+                } else {
+                    b
+                }
+            }
+            (Some(b), None) => b,
+            (None, Some(a)) => a,
+            // Below `2` there's always a palindromic prime above, and vice versa.
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// Return whether `x` is prime, using a deterministic Miller-Rabin test.
+    #[allow(clippy::manual_is_multiple_of)]
+    const fn is_prime(x: u64) -> bool {
+        // These bases are enough to make the test deterministic for every `u64`.
+        const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        if x < 2 {
+            return false;
+        }
+        let mut i = 0;
+        while i < BASES.len() {
+            if x % BASES[i] == 0 {
+                return x == BASES[i];
+            }
+            i += 1;
+        }
+
+        // Write `x - 1` as `d * 2^s` with `d` odd.
+        let s = (x - 1).trailing_zeros();
+        let d = (x - 1) >> s;
+        let mut i = 0;
+        'bases: while i < BASES.len() {
+            let mut y = Self::pow_mod(BASES[i], d, x);
+            i += 1;
+            if y == 1 || y == x - 1 {
+                continue;
+            }
+            let mut r = 1;
+            while r < s {
+                y = (y as u128 * y as u128 % x as u128) as u64;
+                if y == x - 1 {
+                    continue 'bases;
+                }
+                r += 1;
+            }
+            return false;
+        }
+
+        true
+    }
+
+    /// Return `base^exp % modulus`.
+    const fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+        let mut result = 1u64;
+        base %= modulus;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result as u128 * base as u128 % modulus as u128) as u64;
+            }
+            base = (base as u128 * base as u128 % modulus as u128) as u64;
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    /// Return how many palindromes `b` is after `a`. E.g.: `a=99` and `b=121` returns `3`.
+    ///
+    /// **NOTE:** The distance is negative if `b` is before `a`, so it can be passed
+    /// straight to [`Self::offset`] to go from `a` to `b`.
+    pub const fn index_distance(a: &Palindrome, b: &Palindrome) -> isize {
+        b.to_n() as isize - a.to_n() as isize
+    }
+
+    /// Return the amount of palindromes in `a..b`, i.e. including `a` but not `b`.
+    /// E.g.: `a=99` and `b=121` returns `3` (`99`, `101` and `111`).
+    ///
+    /// **NOTE:** Returns `0` if `b` isn't after `a`.
+    /// Same as the length of [`PalindromeIter::from`] with `a` and `b`, without building it.
+    pub const fn count_between(a: Palindrome, b: Palindrome) -> usize {
+        b.to_n().saturating_sub(a.to_n())
+    }
+
+    /// Return the palindrome `delta` palindromes after (or before, if negative) [`Self`].
+    ///
+    /// **NOTE:** Returns [`None`] if the palindrome would be below [`Self::MIN`]
+    /// or above [`Self::MAX`].
+    pub const fn offset(&self, delta: isize) -> Option<Self> {
+        let n = if delta >= 0 {
+            self.to_n().checked_add(delta as usize)
+        } else {
+            self.to_n().checked_sub(delta.unsigned_abs())
+        };
+
+        match n {
+            Some(n) => Self::nth(n),
+            None => None,
+        }
+    }
+
+    /// Return the palindrome `n` palindromes after [`Self`].
+    ///
+    /// **NOTE:** Returns [`Err`] with how many palindromes past [`Self::MAX`] the jump
+    /// would land if the palindrome is larger than [`Self::MAX`].
+    pub const fn try_next_n(&self, n: usize) -> Result<Self, usize> {
+        let remaining = Self::MAX_N - self.to_n();
+        if n > remaining {
+            return Err(n - remaining);
+        }
+
+        match Self::nth(self.to_n() + n) {
+            Some(p) => Ok(p),
+            None => unreachable!(),
+        }
+    }
+
+    /// Return the palindrome `n` palindromes after [`Self`], or [`None`] if it's larger
+    /// than [`Self::MAX`].
+    pub const fn checked_next_n(&self, n: usize) -> Option<Self> {
+        match self.try_next_n(n) {
+            Ok(p) => Some(p),
+            Err(_) => None,
+        }
+    }
+
+    /// Return the palindrome `n` palindromes before [`Self`], or [`None`] if it's smaller
+    /// than [`Self::MIN`].
+    pub const fn checked_previous_n(&self, n: usize) -> Option<Self> {
+        match self.to_n().checked_sub(n) {
+            Some(n) => Self::nth(n),
+            None => None,
+        }
+    }
+
+    /// Return the palindrome `n` palindromes after [`Self`], or [`Self::MAX`] if it's larger.
+    pub const fn saturating_next_n(&self, n: usize) -> Self {
+        match self.try_next_n(n) {
+            Ok(p) => p,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    /// Return the palindrome `n` palindromes before [`Self`], or [`Self::MIN`] if it's smaller.
+    pub const fn saturating_previous_n(&self, n: usize) -> Self {
+        match Self::nth(self.to_n().saturating_sub(n)) {
+            Some(p) => p,
+            None => Self::MIN, // Unreachable, the index is at most `self`'s.
+        }
+    }
+
+    /// Return the palindrome `n` palindromes after [`Self`], wrapping around from
+    /// [`Self::MAX`] to [`Self::MIN`]. E.g.: [`Self::MAX`] with `n=1` returns `0`.
+    pub const fn wrapping_next_n(&self, n: usize) -> Self {
+        const COUNT: usize = Palindrome::MAX_N + 1;
+        match Self::nth((self.to_n() + n % COUNT) % COUNT) {
+            Some(p) => p,
+            None => Self::MIN, // Unreachable, the index is at most `MAX_N`.
+        }
+    }
+
+    /// Return the palindrome `n` palindromes before [`Self`], wrapping around from
+    /// [`Self::MIN`] to [`Self::MAX`]. E.g.: `0` with `n=1` returns [`Self::MAX`].
+    pub const fn wrapping_previous_n(&self, n: usize) -> Self {
+        const COUNT: usize = Palindrome::MAX_N + 1;
+        match Self::nth((self.to_n() + COUNT - n % COUNT) % COUNT) {
+            Some(p) => p,
+            None => Self::MIN, // Unreachable, the index is at most `MAX_N`.
+        }
+    }
+
+    /// Return the first palindromic number that is strictly less than `x`.
+    ///
+    /// **NOTE:** Lowest return-value is [`Self::MIN`].
+    pub const fn strictly_below(x: u64) -> Self {
+        Self::le(x.saturating_sub(1))
+    }
+
+    /// Return the first palindromic number that is strictly greater than `x`.
+    ///
+    /// **NOTE:** Highest return-value is [`Self::MAX`].
+    pub const fn strictly_above(x: u64) -> Self {
+        Self::ge(x.saturating_add(1))
+    }
+
+    /// Return the first palindromic number that is less than or equal to `x`.
+    pub const fn le(x: u64) -> Self {
+        if Palindrome::is_palindrome(x) {
+            return Palindrome(x);
+        }
+
+        let mut digits = Self::to_digits(x);
+        let mut length = digits.len();
+        let half_length = length.div_ceil(2); // As in amount of digits.
+        let mut fh_idx = half_length - 1;
+        let mut sh_idx = half_length;
+        if length % 2 == 1 {
+            sh_idx -= 1; // We don't want center value of uneven number.
+        }
+
+        let mut skip = 0;
+        loop {
+            // 100 -> 99
+            // 372 -> 363
+            // 4847 -> 4774
+            // 4003 -> 3993
+            if digits.get(fh_idx) < digits.get(sh_idx) {
+                digits.narrow_start_end(0, half_length);
+                return Self::construct_saturating(&digits, length);
+            }
+            if digits.get(fh_idx) > digits.get(sh_idx) {
+                // First try to downgrade center value, if it's 0, set to 9 and continue.
+                // Once non-0 value found, -- it.
+                let center_idx = half_length - 1; // Center idx.
+                let mut i = 0;
+                while i < half_length {
+                    let current_digit = digits.get_mut(center_idx - i);
+                    if *current_digit == 0 {
+                        *current_digit = 9;
+                        i += 1;
+                        continue;
+                    }
+                    *current_digit -= 1;
+                    // EDGE CASE: 100 -> 99 (length of first half digits CHANGES).
+                    // EDGE CASE: 10 -> 9 (length of first half digits DOESN'T CHANGE).
+                    if center_idx - i == 0 && *current_digit == 0 {
+                        *current_digit = 9;
+                        if length % 2 == 1 {
+                            skip += 1;
+                        }
+                        length -= 1; // Length always decreases by 1.
+                    }
+                    break;
+                }
+                digits.narrow_start_end(skip, half_length);
+                return Self::construct_saturating(&digits, length);
+            }
+
+            fh_idx -= 1;
+            sh_idx += 1;
+        }
+    }
+
+    /// Return the first palindromic number that is greater than or equal to `x`.
+    ///
+    /// **ATTENTION:** Any value above [`Self::MAX`] will return [`Self::MAX`].
+    pub const fn ge(x: u64) -> Self {
+        if x >= Self::MAX.0 {
+            return Self::MAX;
+        }
+
+        if Palindrome::is_palindrome(x) {
+            return Palindrome(x);
+        }
+
+        let mut digits = Self::to_digits(x);
+        let length = digits.len();
+        let half_length = length.div_ceil(2); // As in amount of digits.
+        let mut fh_idx = half_length - 1;
+        let mut sh_idx = half_length;
+        if length % 2 == 1 {
+            fh_idx -= 1; // We don't want center value of uneven number.
+        }
+
+        loop {
+            if digits.get(fh_idx) > digits.get(sh_idx) {
+                digits.narrow_start_end(0, half_length);
+                return Self::construct_saturating(&digits, length);
+            }
+            if digits.get(fh_idx) < digits.get(sh_idx) {
+                // First try to upgrade center value, if it's 9, set to 0 and continue.
+                // Once non-9 value found, ++ it. 999 is palindrome and can't happen.
+                let center_idx = half_length - 1; // Center idx.
+                let mut i = 0;
+                while i < half_length {
+                    let current_digit = digits.get_mut(center_idx - i);
+                    if *current_digit == 9 {
+                        *current_digit = 0;
+                        i += 1;
+                        continue;
+                    }
+                    *current_digit += 1;
+                    break;
+                }
+                digits.narrow_start_end(0, half_length);
+                return Self::construct_saturating(&digits, length);
+            }
+
+            fh_idx -= 1;
+            sh_idx += 1;
+        }
+    }
+
+    /// Return the palindrome made by mirroring the upper half of `x` over its lower half,
+    /// keeping the amount of digits. E.g.: `1234` returns `1221` and `12345` returns `12321`.
+    ///
+    /// **NOTE:** The upper half is the first `ceil(length / 2)` digits, so for an odd length
+    /// the center digit is kept and not mirrored. The result isn't necessarily the closest
+    /// palindrome (see [`Self::closest`]), and it's `x` itself if `x` is already a palindrome.
+    ///
+    /// **ATTENTION:** Highest return-value is [`Self::MAX`].
+    pub const fn mirror(x: u64) -> Self {
+        let mut digits = Self::to_digits(x);
+        let length = digits.len();
+        digits.narrow_start_end(0, length.div_ceil(2));
+        Self::construct_saturating(&digits, length)
+    }
+}
+
+impl Deref for Palindrome {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Palindrome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Generates palindromes uniformly over their palindrome-indices (see [`Palindrome::nth`]),
+/// from [`Palindrome::MIN`] up to and including [`Palindrome::MAX`].
+/// Shrinking the index shrinks toward smaller palindromes, and ultimately [`Palindrome::MIN`].
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Palindrome {
+    type Parameters = ();
+    type Strategy =
+        proptest::strategy::Map<std::ops::RangeInclusive<usize>, fn(usize) -> Palindrome>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        fn nth(n: usize) -> Palindrome {
+            match Palindrome::nth(n) {
+                Some(palindrome) => palindrome,
+                None => unreachable!(), // Every index up to `MAX_N` has a palindrome.
+            }
+        }
+        (0..=Self::MAX_N).prop_map(nth as fn(usize) -> Palindrome)
+    }
+}
+
+/// Builds a palindrome from a palindrome-index (see [`Palindrome::nth`]) taken from the input,
+/// so every value is a valid palindrome from [`Palindrome::MIN`] up to [`Palindrome::MAX`].
+/// Once the input runs out, this returns [`Palindrome::MIN`].
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Palindrome {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let n = u.int_in_range(0..=Self::MAX_N)?;
+        match Self::nth(n) {
+            Some(palindrome) => Ok(palindrome),
+            None => unreachable!(), // Every index up to `MAX_N` has a palindrome.
+        }
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(size_of::<usize>()))
+    }
+}
+
+impl From<Palindrome> for u64 {
+    fn from(value: Palindrome) -> Self {
+        value.0
+    }
+}
+
+impl From<&Palindrome> for u64 {
+    fn from(value: &Palindrome) -> Self {
+        value.0
+    }
+}
+
+impl From<Palindrome> for u128 {
+    fn from(value: Palindrome) -> Self {
+        value.0 as u128
+    }
+}
+
+impl From<&Palindrome> for u128 {
+    fn from(value: &Palindrome) -> Self {
+        value.0 as u128
+    }
+}
+
+impl From<Palindrome> for i128 {
+    fn from(value: Palindrome) -> Self {
+        value.0 as i128
+    }
+}
+
+impl From<&Palindrome> for i128 {
+    fn from(value: &Palindrome) -> Self {
+        value.0 as i128
+    }
+}
+
+macro_rules! try_from_palindrome {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<Palindrome> for $t {
+                type Error = TryFromIntError;
+
+                fn try_from(value: Palindrome) -> Result<Self, Self::Error> {
+                    <$t>::try_from(value.0)
+                }
+            }
+        )*
+    };
+}
+
+try_from_palindrome!(u8, u16, u32, usize, i8, i16, i32, i64, isize, NonZeroU64);
+
+impl TryFrom<u64> for Palindrome {
+    type Error = PalindromeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl PartialEq<u64> for Palindrome {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Palindrome> for u64 {
+    fn eq(&self, other: &Palindrome) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<u64> for Palindrome {
+    fn ge(&self, other: &u64) -> bool {
+        self.0 >= *other
+    }
+
+    fn gt(&self, other: &u64) -> bool {
+        self.0 > *other
+    }
+
+    fn le(&self, other: &u64) -> bool {
+        self.0 <= *other
+    }
+
+    fn lt(&self, other: &u64) -> bool {
+        self.0 < *other
+    }
+
+    fn partial_cmp(&self, other: &u64) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Palindrome> for u64 {
+    fn ge(&self, other: &Palindrome) -> bool {
+        *self >= other.0
+    }
+
+    fn gt(&self, other: &Palindrome) -> bool {
+        *self > other.0
+    }
+
+    fn le(&self, other: &Palindrome) -> bool {
+        *self <= other.0
+    }
+
+    fn lt(&self, other: &Palindrome) -> bool {
+        *self < other.0
+    }
+
+    fn partial_cmp(&self, other: &Palindrome) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+/// Return `lhs + rhs`.
+///
+/// The operators use these helpers so that with the `strict-arithmetic` feature,
+/// overflow panics in every build profile instead of only with debug assertions.
+#[inline]
+#[track_caller]
+fn add_u64(lhs: u64, rhs: u64) -> u64 {
+    if cfg!(feature = "strict-arithmetic") {
+        lhs.checked_add(rhs).expect("attempt to add with overflow")
+    } else {
+        lhs + rhs
+    }
+}
+
+/// Return `lhs - rhs`.
+#[inline]
+#[track_caller]
+fn sub_u64(lhs: u64, rhs: u64) -> u64 {
+    if cfg!(feature = "strict-arithmetic") {
+        lhs.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    } else {
+        lhs - rhs
+    }
+}
+
+/// Return `lhs * rhs`.
+#[inline]
+#[track_caller]
+fn mul_u64(lhs: u64, rhs: u64) -> u64 {
+    if cfg!(feature = "strict-arithmetic") {
+        lhs.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    } else {
+        lhs * rhs
+    }
+}
+
+/// Return `lhs << rhs`.
+#[inline]
+#[track_caller]
+fn shl_u64(lhs: u64, rhs: u64) -> u64 {
+    if cfg!(feature = "strict-arithmetic") {
+        u32::try_from(rhs)
+            .ok()
+            .and_then(|rhs| lhs.checked_shl(rhs))
+            .expect("attempt to shift left with overflow")
+    } else {
+        lhs << rhs
+    }
+}
+
+/// Return `lhs >> rhs`.
+#[inline]
+#[track_caller]
+fn shr_u64(lhs: u64, rhs: u64) -> u64 {
+    if cfg!(feature = "strict-arithmetic") {
+        u32::try_from(rhs)
+            .ok()
+            .and_then(|rhs| lhs.checked_shr(rhs))
+            .expect("attempt to shift right with overflow")
+    } else {
+        lhs >> rhs
+    }
+}
+
+impl Add<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: u64) -> Self::Output {
+        add_u64(self.0, rhs)
+    }
+}
+
+forward_ref_binop!(impl Add, add for Palindrome, u64);
+
+impl Add<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Palindrome) -> Self::Output {
+        add_u64(self, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Add, add for u64, Palindrome);
+
+impl Add<Palindrome> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Palindrome) -> Self::Output {
+        add_u64(self.0, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Add, add for Palindrome, Palindrome);
+
+impl AddAssign<Palindrome> for u64 {
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Palindrome) {
+        *self = add_u64(*self, rhs.0);
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for u64, Palindrome);
+
+impl BitAnd<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn bitand(self, rhs: u64) -> Self::Output {
+        self.0 & rhs
+    }
+}
+
+forward_ref_binop!(impl BitAnd, bitand for Palindrome, u64);
+
+impl BitAnd<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn bitand(self, rhs: Palindrome) -> Self::Output {
+        self & rhs.0
+    }
+}
+
+forward_ref_binop!(impl BitAnd, bitand for u64, Palindrome);
+
+impl BitAndAssign<Palindrome> for u64 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Palindrome) {
+        *self &= rhs.0
+    }
+}
+
+forward_ref_op_assign!(impl BitAndAssign, bitand_assign for u64, Palindrome);
+
+impl BitOr<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn bitor(self, rhs: u64) -> Self::Output {
+        self.0 | rhs
+    }
+}
+
+forward_ref_binop!(impl BitOr, bitor for Palindrome, u64);
+
+impl BitOr<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn bitor(self, rhs: Palindrome) -> Self::Output {
+        self | rhs.0
+    }
+}
+
+forward_ref_binop!(impl BitOr, bitor for u64, Palindrome);
+
+impl BitOrAssign<Palindrome> for u64 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Palindrome) {
+        *self |= rhs.0
+    }
+}
+
+forward_ref_op_assign!(impl BitOrAssign, bitor_assign for u64, Palindrome);
+
+impl BitXor<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn bitxor(self, rhs: u64) -> Self::Output {
+        self.0 ^ rhs
+    }
+}
+
+forward_ref_binop!(impl BitXor, bitxor for Palindrome, u64);
+
+impl BitXor<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn bitxor(self, rhs: Palindrome) -> Self::Output {
+        self ^ rhs.0
+    }
+}
+
+forward_ref_binop!(impl BitXor, bitxor for u64, Palindrome);
+
+impl BitXorAssign<Palindrome> for u64 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Palindrome) {
+        *self ^= rhs.0
+    }
+}
+
+forward_ref_op_assign!(impl BitXorAssign, bitxor_assign for u64, Palindrome);
+
+impl Div<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn div(self, rhs: u64) -> Self::Output {
+        self.0 / rhs
+    }
+}
+
+forward_ref_binop!(impl Div, div for Palindrome, u64);
+
+impl Div<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn div(self, rhs: Palindrome) -> Self::Output {
+        self / rhs.0
+    }
+}
+
+forward_ref_binop!(impl Div, div for u64, Palindrome);
+
+impl Div<Palindrome> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn div(self, rhs: Palindrome) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+forward_ref_binop!(impl Div, div for Palindrome, Palindrome);
+
+impl DivAssign<Palindrome> for u64 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Palindrome) {
+        *self /= rhs.0
+    }
+}
+
+forward_ref_op_assign!(impl DivAssign, div_assign for u64, Palindrome);
+
+impl Mul<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: u64) -> Self::Output {
+        mul_u64(self.0, rhs)
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Palindrome, u64);
+
+impl Mul<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: Palindrome) -> Self::Output {
+        mul_u64(self, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for u64, Palindrome);
+
+impl Mul<Palindrome> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: Palindrome) -> Self::Output {
+        mul_u64(self.0, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Palindrome, Palindrome);
+
+impl MulAssign<Palindrome> for u64 {
+    #[inline]
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: Palindrome) {
+        *self = mul_u64(*self, rhs.0)
+    }
+}
+
+forward_ref_op_assign!(impl MulAssign, mul_assign for u64, Palindrome);
+
+impl Not for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        !self.0
+    }
+}
+
+forward_ref_unop!(impl Not, not for Palindrome);
+
+impl Rem<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: u64) -> Self::Output {
+        self.0 % rhs
+    }
+}
+
+forward_ref_binop!(impl Rem, rem for Palindrome, u64);
+
+impl Rem<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: Palindrome) -> Self::Output {
+        self % rhs.0
+    }
+}
+
+forward_ref_binop!(impl Rem, rem for u64, Palindrome);
+
+impl Rem<Palindrome> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: Palindrome) -> Self::Output {
+        self.0 % rhs.0
+    }
+}
+
+forward_ref_binop!(impl Rem, rem for Palindrome, Palindrome);
+
+impl RemAssign<Palindrome> for u64 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Palindrome) {
+        *self %= rhs.0
+    }
+}
+
+forward_ref_op_assign!(impl RemAssign, rem_assign for u64, Palindrome);
+
+impl Shl<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn shl(self, rhs: u64) -> Self::Output {
+        shl_u64(self.0, rhs)
+    }
+}
+
+forward_ref_binop!(impl Shl, shl for Palindrome, u64);
+
+impl Shl<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn shl(self, rhs: Palindrome) -> Self::Output {
+        shl_u64(self, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Shl, shl for u64, Palindrome);
+
+impl ShlAssign<Palindrome> for u64 {
+    #[inline]
+    #[track_caller]
+    fn shl_assign(&mut self, rhs: Palindrome) {
+        *self = shl_u64(*self, rhs.0);
+    }
+}
+
+forward_ref_op_assign!(impl ShlAssign, shl_assign for u64, Palindrome);
+
+impl Shr<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn shr(self, rhs: u64) -> Self::Output {
+        shr_u64(self.0, rhs)
+    }
+}
+
+forward_ref_binop!(impl Shr, shr for Palindrome, u64);
+
+impl Shr<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn shr(self, rhs: Palindrome) -> Self::Output {
+        shr_u64(self, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Shr, shr for u64, Palindrome);
+
+impl ShrAssign<Palindrome> for u64 {
+    #[inline]
+    #[track_caller]
+    fn shr_assign(&mut self, rhs: Palindrome) {
+        *self = shr_u64(*self, rhs.0);
+    }
+}
+
+forward_ref_op_assign!(impl ShrAssign, shr_assign for u64, Palindrome);
+
+impl Sub<u64> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: u64) -> Self::Output {
+        sub_u64(self.0, rhs)
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for Palindrome, u64);
+
+impl Sub<Palindrome> for u64 {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: Palindrome) -> Self::Output {
+        sub_u64(self, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for u64, Palindrome);
+
+impl Sub<Palindrome> for Palindrome {
+    type Output = u64;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: Palindrome) -> Self::Output {
+        sub_u64(self.0, rhs.0)
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for Palindrome, Palindrome);
+
+impl SubAssign<Palindrome> for u64 {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Palindrome) {
+        *self = sub_u64(*self, rhs.0)
+    }
+}
+
+forward_ref_op_assign!(impl SubAssign, sub_assign for u64, Palindrome);
+
+/// Step `rhs` palindromes forward, i.e. in index space instead of value space.
+/// E.g.: `121 += 2` is `141`.
+///
+/// **NOTE:** Saturates at [`Palindrome::MAX`], see [`Palindrome::saturating_next_n`].
+impl AddAssign<usize> for Palindrome {
+    #[inline]
+    fn add_assign(&mut self, rhs: usize) {
+        *self = self.saturating_next_n(rhs);
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for Palindrome, usize);
+
+/// Step `rhs` palindromes backward, i.e. in index space instead of value space.
+/// E.g.: `121 -= 2` is `101`.
+///
+/// **NOTE:** Saturates at [`Palindrome::MIN`], see [`Palindrome::saturating_previous_n`].
+impl SubAssign<usize> for Palindrome {
+    #[inline]
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = self.saturating_previous_n(rhs);
+    }
+}
+
+forward_ref_op_assign!(impl SubAssign, sub_assign for Palindrome, usize);
+
+pub struct PalindromeIter {
+    from: Palindrome,
+    to: u64,
+}
+
+impl PalindromeIter {
+    /// Return an iterator over all palindromes in the range `from..to`.
+    ///
+    /// **NOTE:** [`std::iter::Step`] is currently nightly/experimental,
+    /// so this will have to do for now. If `from` is greater than or equal to `to`
+    /// (or there are no palindromes in between), the iterator is empty.
+    pub fn from<T: Into<u64>, U: Into<u64>>(from: T, to: U) -> Self {
+        let (from, to) = (from.into(), to.into());
+        let first = Palindrome::ge(from);
+        // An inverted range would underflow in `Self::len`, so it's made empty instead.
+        let to = if from > Palindrome::MAX.0 || to < first.0 {
+            first.0
+        } else {
+            to
+        };
+
+        Self { from: first, to }
+    }
+
+    /// Return an iterator over all palindromes in `range`, which can be any kind of range.
+    /// E.g.: `100..=999` iterates over every 3-digit palindrome.
+    ///
+    /// **NOTE:** An unbounded end includes [`Palindrome::MAX`], and so does an inclusive end
+    /// of [`Palindrome::MAX`] or above. Just like with [`Self::from`], an empty or inverted
+    /// range makes the iterator empty.
+    pub fn from_range(range: impl RangeBounds<u64>) -> Self {
+        let (from, to) = Palindrome::half_open(&range);
+        Self::from(from, to)
+    }
+
+    /// Return an iterator over the first `n` palindromes.
+    ///
+    /// **NOTE:** Any palindrome larger than [`Palindrome::MAX`] won't be included
+    /// and will instead be [`None`].
+    pub const fn first_n(n: usize) -> Self {
+        Self::first_n_from(n, Palindrome(0))
+    }
+
+    /// Return an iterator over the first `n` palindromes from the first palindrome `from`.
+    ///
+    /// **NOTE:** Any palindrome larger than [`Palindrome::MAX`] won't be included
+    /// and will instead be [`None`].
+    pub const fn first_n_from(n: usize, from: Palindrome) -> Self {
+        let to = match Palindrome::nth(from.to_n().saturating_add(n)) {
+            Some(p) => p.0,
+            None => Palindrome::MAX.0 + 1, // Include MAX.
+        };
+
+        Self { from, to }
+    }
+
+    /// Return an iterator over the first `n` palindromes from the first palindrome `from`,
+    /// like [`Self::first_n_from`].
+    ///
+    /// **NOTE:** Returns [`None`] if fewer than `n` palindromes are left from `from`
+    /// up to and including [`Palindrome::MAX`], instead of silently yielding fewer.
+    pub const fn checked_first_n_from(n: usize, from: Palindrome) -> Option<Self> {
+        let pal_iter = Self::first_n_from(n, from);
+        if pal_iter.len() < n {
+            return None;
+        }
+
+        Some(pal_iter)
+    }
+
+    /// Return an iterator over the last `n` palindromes up to and including the palindrome `to`,
+    /// in ascending order. E.g.: `n=3` and `to=121` yields `101`, `111` and `121`.
+    ///
+    /// **NOTE:** If there are fewer than `n` palindromes up to `to`, the iterator starts
+    /// at [`Palindrome::MIN`] instead.
+    pub const fn last_n_ending_at(n: usize, to: Palindrome) -> Self {
+        if n == 0 {
+            return Self { from: to, to: to.0 };
+        }
+
+        let from = match Palindrome::nth(to.to_n().saturating_sub(n - 1)) {
+            Some(p) => p,
+            None => Palindrome::MIN, // Unreachable, the index is at most `to`'s.
+        };
+
+        Self { from, to: to.0 + 1 }
+    }
+
+    /// Return an iterator over the first `n` palindromes from the first palindrome `from`,
+    /// stopping early at the last palindrome less than or equal to `max`.
+    pub const fn first_n_until(n: usize, from: Palindrome, max: u64) -> Self {
+        let mut iter = Self::first_n_from(n, from);
+        let until = Palindrome::le(max).0 + 1;
+        if until < iter.to {
+            iter.to = until;
+        }
+        if iter.to < from.0 {
+            iter.to = from.0; // Empty, `from` is already above `max`.
+        }
+
+        iter
+    }
+
+    /// Return the length of [`Self`].
+    ///
+    /// **NOTE:** This function is constant time and much faster than [`Self::count`] for any non-trivial range.
+    pub const fn len(&self) -> usize {
+        // Calculate length from 0..self.from
+        let over_counted = Self::len_from_0(self.from.0);
+
+        // Calculate length from 0..self.to
+        let over_count = Self::len_from_0(self.to);
+
+        over_count - over_counted
+    }
+
+    /// Return whether [`Self`] contains no palindromes.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the first palindrome in [`Self`] that is greater than or equal to `x`,
+    /// skipping every palindrome before it.
+    ///
+    /// **NOTE:** This function is constant time and much faster than [`Self::find`]
+    /// for any non-trivial range.
+    pub fn find_ge(&mut self, x: u64) -> Option<Palindrome> {
+        if x > Palindrome::MAX.0 {
+            self.to = self.from.0; // Exhaust.
+            return None;
+        }
+
+        if x > self.from.0 {
+            self.from = Palindrome::ge(x);
+        }
+        if self.from.0 >= self.to {
+            self.to = self.from.0; // Exhaust.
+            return None;
+        }
+
+        self.next()
+    }
+
+    /// Return the position of `palindrome` in [`Self`], or [`None`] if it's outside of [`Self`].
+    ///
+    /// **NOTE:** Equivalent to `self.position(|p| p == palindrome)`, but doesn't iterate.
+    pub const fn index_of(&self, palindrome: Palindrome) -> Option<usize> {
+        if palindrome.0 < self.from.0 || palindrome.0 >= self.to {
+            return None;
+        }
+
+        Some(palindrome.to_n() - self.from.to_n())
+    }
+
+    /// Return [`Self`] without the palindromes at or above `limit`.
+    ///
+    /// **NOTE:** Equivalent to `self.take_while(|p| p.0 < limit)`,
+    /// but doesn't iterate and keeps [`Self::len`] fast.
+    pub const fn take_while_below(self, limit: u64) -> Self {
+        let to = if limit < self.to { limit } else { self.to };
+        Self {
+            from: self.from,
+            // Never end before the start, so the range stays empty rather than inverted.
+            to: if to < self.from.0 { self.from.0 } else { to },
+        }
+    }
+
+    /// Return [`Self`] without the palindromes below `limit`.
+    ///
+    /// **NOTE:** Equivalent to `self.skip_while(|p| p.0 < limit)`,
+    /// but doesn't iterate and keeps [`Self::len`] fast.
+    pub const fn skip_while_below(self, limit: u64) -> Self {
+        if limit <= self.from.0 {
+            return self;
+        }
+        if limit > Palindrome::MAX.0 {
+            // No palindrome is at or above `limit`.
+            return Self {
+                from: Palindrome::MAX,
+                to: Palindrome::MAX.0,
+            };
+        }
+
+        let from = Palindrome::ge(limit);
+        Self {
+            from,
+            to: if self.to < from.0 { from.0 } else { self.to },
+        }
+    }
+
+    /// Return [`Self`] split into two contiguous iterators, where the first yields
+    /// the first `n` palindromes and the second yields the rest.
+    /// E.g.: the range `5..34` with `n=3` returns the ranges `5..8` and `8..34`.
+    ///
+    /// **NOTE:** If `n` is at least [`Self::len`], the second iterator is empty.
+    pub const fn split_at(self, n: usize) -> (Self, Self) {
+        if n >= self.len() {
+            let empty = Self {
+                from: self.from,
+                to: self.from.0,
+            };
+            return (self, empty);
+        }
+
+        let split = match Palindrome::nth(self.from.to_n() + n) {
+            Some(p) => p,
+            None => Palindrome::MAX, // Unreachable, the index is inside of `self`.
+        };
+        (
+            Self {
+                from: self.from,
+                to: split.0,
+            },
+            Self {
+                from: split,
+                to: self.to,
+            },
+        )
+    }
+
+    /// Return an iterator over [`Self`] in chunks of `size` palindromes.
+    /// Every chunk except possibly the last is exactly `size` palindromes long.
+    ///
+    /// **NOTE:** Will panic if `size` is `0`.
+    pub fn chunks(self, size: usize) -> PalindromeChunks {
+        assert!(size != 0, "chunk size must be non-zero");
+        PalindromeChunks { iter: self, size }
+    }
+
+    /// Return an iterator over every run of `size` consecutive palindromes in [`Self`],
+    /// advancing by one palindrome at a time, like [`slice::windows`].
+    /// E.g.: the range `7..30` with `size=3` yields `[7, 8, 9]`, `[8, 9, 11]` and `[9, 11, 22]`.
+    ///
+    /// **NOTE:** Yields nothing if [`Self`] has fewer than `size` palindromes.
+    /// Will panic if `size` is `0`.
+    pub fn windows(self, size: usize) -> PalindromeWindows {
+        assert!(size != 0, "window size must be non-zero");
+        PalindromeWindows {
+            iter: self,
+            size,
+            window: VecDeque::new(),
+            filled: false,
+        }
+    }
+
+    /// Return an ascending iterator over the palindromes in either [`Self`] or `other`,
+    /// yielding palindromes in both only once.
+    pub const fn merge(self, other: PalindromeIter) -> PalindromeMerge {
+        PalindromeMerge {
+            left: self,
+            right: other,
+        }
+    }
+
+    /// Return an iterator over [`Self`] from the highest palindrome down, yielding every
+    /// palindrome together with its palindrome-index. E.g.: the range `100..125` yields
+    /// `(21, 121)`, `(20, 111)` and `(19, 101)`.
+    ///
+    /// **NOTE:** The indices are the same as [`Palindrome::to_n`], so
+    /// `Palindrome::nth(index)` returns the palindrome it is paired with.
+    pub const fn rev_with_index(self) -> PalindromeRevWithIndex {
+        PalindromeRevWithIndex { iter: self }
+    }
+
+    /// Return an iterator over [`Self`] yielding every palindrome together with the amount
+    /// of palindromes still to come after it. E.g.: the range `5..8` yields `(5, 2)`, `(6, 1)` and `(7, 0)`.
+    ///
+    /// **NOTE:** The length is only calculated once and then counted down,
+    /// so this is cheaper than calling [`Self::len`] after every palindrome.
+    pub const fn with_remaining(self) -> PalindromeWithRemaining {
+        let remaining = self.len();
+        PalindromeWithRemaining {
+            iter: self,
+            remaining,
+        }
+    }
+
+    /// Return an iterator over [`Self`] where every palindrome is at least `gap` above
+    /// the previous one. E.g.: the range `0..200` with `gap=50` yields `0`, `55`, `111` and `161`.
+    ///
+    /// **NOTE:** The first palindrome of [`Self`] is always yielded first.
+    /// `gap=0` yields every palindrome, just like `gap=1`.
+    pub const fn step_by_value(self, gap: u64) -> PalindromeStepByValue {
+        PalindromeStepByValue {
+            iter: self,
+            gap,
+            limit: 0,
+        }
+    }
+
+    /// Return an iterator over the `u64` values of the palindromes in [`Self`].
+    pub const fn values(self) -> PalindromeValues {
+        PalindromeValues { iter: self }
+    }
+
+    /// Return an iterator that calls `f` on the `u64` value of every palindrome in [`Self`].
+    /// E.g.: the range `5..34` with `f=|x| x * 2` yields `10`, `12`, `14`, `16`, `18`, `22`, `44`
+    /// and `66`.
+    ///
+    /// **NOTE:** Unlike [`Iterator::map`], the returned iterator keeps [`Self::len`].
+    pub const fn map_values<T, F: FnMut(u64) -> T>(self, f: F) -> PalindromeMapValues<F> {
+        PalindromeMapValues { iter: self, f }
+    }
+
+    /// Return the `u64` values of the palindromes in [`Self`].
+    ///
+    /// **NOTE:** The capacity of the [`Vec`] is reserved up front with [`Self::len`],
+    /// so only a single allocation is made.
+    pub fn collect_values(self) -> Vec<u64> {
+        let mut values = Vec::with_capacity(self.len());
+        values.extend(self.map(|palindrome| palindrome.0));
+
+        values
+    }
+
+    /// Return every palindrome in [`Self`] in decimal, separated by `sep`.
+    /// E.g.: the range `5..34` with `sep=", "` returns `"5, 6, 7, 8, 9, 11, 22, 33"`.
+    ///
+    /// **NOTE:** The capacity of the string is reserved up front with [`Self::len`],
+    /// so only a single allocation is made.
+    pub fn join(self, sep: &str) -> String {
+        use std::fmt::Write as _;
+
+        let len = self.len();
+        if len == 0 {
+            return String::new();
+        }
+        let max_width = Palindrome::le(self.to - 1).0.checked_ilog10().unwrap_or(0) as usize + 1;
+        let mut joined = String::with_capacity(len * max_width + (len - 1) * sep.len());
+        for (idx, palindrome) in self.enumerate() {
+            if idx > 0 {
+                joined.push_str(sep);
+            }
+            // Writing to a `String` never fails.
+            let _ = write!(joined, "{}", palindrome.0);
+        }
+
+        joined
+    }
+
+    /// Write every palindrome in [`Self`] to `w` in decimal, separated by `sep`,
+    /// and return the amount of palindromes written.
+    ///
+    /// **NOTE:** Every palindrome results in at least one call to [`Write::write_all`],
+    /// so wrap unbuffered writers (like files and sockets) in a [`std::io::BufWriter`].
+    pub fn write_to<W: Write>(self, w: &mut W, sep: &[u8]) -> io::Result<usize> {
+        let mut count = 0;
+        let mut buf = [0u8; Palindrome::MAX_LEN];
+        for palindrome in self {
+            if count > 0 {
+                w.write_all(sep)?;
+            }
+
+            let mut x = palindrome.0;
+            let mut start = buf.len();
+            loop {
+                start -= 1;
+                buf[start] = b'0' + (x % 10) as u8;
+                x /= 10;
+                if x == 0 {
+                    break;
+                }
+            }
+            w.write_all(&buf[start..])?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Return a mask over the range `from..to`, where the element at `x - from`
+    /// is `true` if `x` is a palindrome in [`Self`].
+    ///
+    /// **NOTE:** This function only visits the palindromes in the range, so it's much faster
+    /// than checking every number in the range with [`IsPalindrome::is_palindrome`].
+    pub fn to_mask(&self, from: u64, to: u64) -> Vec<bool> {
+        let mut mask = vec![false; to.saturating_sub(from) as usize];
+        let start = if from > self.from.0 {
+            Palindrome::ge(from)
+        } else {
+            self.from
+        };
+        let end = if to < self.to { to } else { self.to };
+        if start.0 < from {
+            return mask; // `from` is above Palindrome::MAX.
+        }
+
+        for palindrome in (PalindromeIter {
+            from: start,
+            to: end,
+        }) {
+            mask[(palindrome.0 - from) as usize] = true;
+        }
+
+        mask
+    }
+
+    /// Return how many palindromes in [`Self`] have each amount of digits,
+    /// as `(digit_length, count)` pairs ordered by `digit_length`.
+    ///
+    /// **NOTE:** This function doesn't iterate and is much faster than counting
+    /// the palindromes one by one for any non-trivial range.
+    pub fn digit_length_histogram(&self) -> Vec<(u32, usize)> {
+        let mut histogram = Vec::new();
+        if self.is_empty() {
+            return histogram;
+        }
+
+        let first_length = Palindrome::to_digits(self.from.0).len() as u32;
+        let last_length = Palindrome::to_digits(self.to - 1).len() as u32;
+        for length in first_length..=last_length {
+            let lowest = if length == 1 {
+                0
+            } else {
+                10u64.pow(length - 1)
+            };
+            let start = lowest.max(self.from.0);
+            let end = match 10u64.checked_pow(length) {
+                Some(highest) => highest.min(self.to),
+                None => self.to, // 20 digits.
+            };
+            let count = Self::len_from_0(end) - Self::len_from_0(start);
+            if count > 0 {
+                histogram.push((length, count));
+            }
+        }
+
+        histogram
+    }
+
+    /// Return the sum of all palindromes in [`Self`].
+    ///
+    /// **NOTE:** This function doesn't construct any palindromes and is much faster
+    /// than summing the iterator for any non-trivial range.
+    pub const fn sum_values(&self) -> u128 {
+        Self::sum_from_0(self.to) - Self::sum_from_0(self.from.0)
+    }
+
+    /// Return the product of all palindromes in [`Self`], or [`None`] if it overflows a [`u128`].
+    /// E.g.: the range `1..12` returns `3991680` (`9! * 11`).
+    ///
+    /// **NOTE:** A range including `0` returns `0`, and an empty range returns `1`.
+    /// Products grow fast, so only small ranges of small palindromes don't overflow.
+    pub fn product_values(&self) -> Option<u128> {
+        if self.is_empty() {
+            return Some(1);
+        }
+        if self.from.0 == 0 {
+            return Some(0);
+        }
+
+        let mut pal_iter = Self {
+            from: self.from,
+            to: self.to,
+        };
+        pal_iter.try_fold(1u128, |product, palindrome| {
+            product.checked_mul(palindrome.0 as u128)
+        })
+    }
+
+    // Doesn't include `to`.
+    const fn sum_from_0(to: u64) -> u128 {
+        if to == 0 {
+            return 0;
+        }
+
+        let digits = Palindrome::to_digits(to);
+        let length = digits.len();
+
+        // Sum every palindrome with fewer digits than `to`.
+        let mut sum = 0;
+        let mut n_digits = 1;
+        while n_digits < length {
+            let half_length = n_digits.div_ceil(2);
+            let lowest_half = if n_digits == 1 {
+                0 // 0 is a 1-digit palindrome too.
+            } else {
+                10u128.pow(half_length as u32 - 1)
+            };
+            sum += Self::sum_of_halves(lowest_half, 10u128.pow(half_length as u32), n_digits);
+            n_digits += 1;
+        }
+
+        // Sum the palindromes with as many digits as `to` whose first half is lower than `to`'s.
+        let half_length = length.div_ceil(2);
+        let mut first_half = 0u128;
+        let mut idx = 0;
+        while idx < half_length {
+            first_half = first_half * 10 + digits.get(idx) as u128;
+            idx += 1;
+        }
+        let lowest_half = if length == 1 {
+            0
+        } else {
+            10u128.pow(half_length as u32 - 1)
+        };
+        sum += Self::sum_of_halves(lowest_half, first_half, length);
+
+        // The palindrome made from `to`'s own first half might be lower than `to` too.
+        let own = Self::sum_of_halves(first_half, first_half + 1, length);
+        if own < to as u128 {
+            sum += own;
+        }
+
+        sum
+    }
+
+    /// Sum of all `length`-digit palindromes whose first half is in the range `from..to`.
+    const fn sum_of_halves(from: u128, to: u128, length: usize) -> u128 {
+        if from >= to {
+            return 0;
+        }
+
+        // A palindrome is its first half shifted left, plus the mirror of the first half.
+        // E.g.: 12321 = 123 * 100 + 21, where the 1 of 21 is the first digit of 123.
+        let half_length = length.div_ceil(2);
+        let mirror_length = length - half_length;
+        let shifted = (from + to - 1) * (to - from) / 2 * 10u128.pow(mirror_length as u32);
+
+        // The j-th digit (from the left) of the first half lands on position j of the mirror.
+        let mut mirrored = 0;
+        let mut j = 0;
+        while j < mirror_length {
+            let position = (half_length - 1 - j) as u32;
+            let digit_sum =
+                Self::digit_sum_below(to, position) - Self::digit_sum_below(from, position);
+            mirrored += digit_sum * 10u128.pow(j as u32);
+            j += 1;
+        }
+
+        shifted + mirrored
+    }
+
+    /// Sum of the digit at `position` (0 being the ones) of every number in the range `0..to`.
+    const fn digit_sum_below(to: u128, position: u32) -> u128 {
+        let unit = 10u128.pow(position);
+        let cycle = unit * 10;
+        let full_cycles = to / cycle;
+        let rest = to % cycle;
+        let current_digit = rest / unit;
+
+        // Every full cycle contains each digit 0-9 `unit` times.
+        full_cycles * 45 * unit
+            + current_digit * (current_digit.saturating_sub(1)) / 2 * unit
+            + current_digit * (rest % unit)
+    }
+
+    // Doesn't include `to`.
+    const fn len_from_0(to: u64) -> usize {
+        if to == 0 {
+            return 0;
+        }
+
+        let digits = Palindrome::to_digits(to);
+        let half_length = digits.len().div_ceil(2);
+
+        let mut count = Self::palindromes_in_n_digits(digits.len() as u8) as isize;
+        let mut front_part_as_num = 0isize;
+        let mut to_subtract = 1isize;
+        let mut idx = 0;
+        while idx < half_length {
+            to_subtract *= 10;
+            front_part_as_num *= 10;
+            front_part_as_num += digits.get(idx) as isize;
+            idx += 1;
+        }
+        count += front_part_as_num - to_subtract;
+
+        // If second half of the number is higher than first half, +1.
+        let (mut i, mut j) = (half_length, half_length + 1);
+        if digits.len() % 2 == 1 {
+            i -= 1; // Don't want to compare center value of uneven digit number.
+        }
+        // Find the first digits from center and out that differ.
+        while i > 0 && digits.get(i - 1) == digits.get(j - 1) {
+            i -= 1;
+            j += 1;
+        }
+        if i > 0 && digits.get(i - 1) < digits.get(j - 1) {
+            count += 1; // Second half is larger, so ++ that bi***.
+        }
+
+        count as usize
+    }
+
+    // Will crash if n > Self::MAX_N.
+    const fn palindromes_in_n_digits(n: u8) -> usize {
+        const N_DIGIT_NUMBER_PALINDROME: [usize; 21] = [
+            0,
+            10,
             19,
             109,
             199,
@@ -877,149 +4333,3500 @@ impl PalindromeIter {
             19999999999,
         ];
 
-        return N_DIGIT_NUMBER_PALINDROME[n as usize];
+        N_DIGIT_NUMBER_PALINDROME[n as usize]
+    }
+}
+
+impl Iterator for PalindromeIter {
+    type Item = Palindrome;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.from < self.to {
+            let next_palindrome = self.from;
+            if next_palindrome == Palindrome::MAX {
+                // MAX has no next palindrome, so the iterator is exhausted.
+                self.to = Palindrome::MAX.0;
+            } else {
+                self.from = self.from.next();
+            }
+            Some(next_palindrome)
+        } else {
+            None
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.from < self.to {
+            Some(Palindrome::le(self.to - 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl DoubleEndedIterator for PalindromeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.from < self.to {
+            // The highest palindrome in the range is never below `from`, which is a palindrome.
+            let last_palindrome = Palindrome::le(self.to - 1);
+            self.to = last_palindrome.0;
+            Some(last_palindrome)
+        } else {
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.to = self.from.0; // Exhaust.
+            return None;
+        }
+
+        // `n` is below the length, so the index is inside of `self`.
+        let palindrome = Palindrome::nth(Self::len_from_0(self.to) - 1 - n)?;
+        self.to = palindrome.0;
+        Some(palindrome)
+    }
+}
+
+/// A descending iterator over a [`PalindromeIter`], with the palindrome-index of every palindrome.
+///
+/// Created by [`PalindromeIter::rev_with_index`].
+pub struct PalindromeRevWithIndex {
+    iter: PalindromeIter,
+}
+
+impl PalindromeRevWithIndex {
+    /// Return the amount of palindromes left in [`Self`].
+    pub const fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    /// Return whether [`Self`] has no palindromes left.
+    pub const fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+impl Iterator for PalindromeRevWithIndex {
+    type Item = (usize, Palindrome);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let palindrome = self.iter.next_back()?;
+        Some((palindrome.to_n(), palindrome))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl ExactSizeIterator for PalindromeRevWithIndex {}
+
+/// An iterator over a [`PalindromeIter`] in chunks of palindromes.
+///
+/// Created by [`PalindromeIter::chunks`].
+pub struct PalindromeChunks {
+    iter: PalindromeIter,
+    size: usize,
+}
+
+impl PalindromeChunks {
+    /// Return the amount of chunks left in [`Self`].
+    pub const fn len(&self) -> usize {
+        self.iter.len().div_ceil(self.size)
+    }
+
+    /// Return whether [`Self`] has no chunks left.
+    pub const fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+impl Iterator for PalindromeChunks {
+    type Item = Vec<Palindrome>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk_len = self.iter.len().min(self.size);
+        if chunk_len == 0 {
+            return None;
+        }
+
+        let mut chunk = Vec::with_capacity(chunk_len);
+        chunk.extend(self.iter.by_ref().take(chunk_len));
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl ExactSizeIterator for PalindromeChunks {}
+
+/// An iterator over overlapping windows of consecutive palindromes in a [`PalindromeIter`].
+///
+/// Created by [`PalindromeIter::windows`].
+pub struct PalindromeWindows {
+    iter: PalindromeIter,
+    size: usize,
+    window: VecDeque<Palindrome>,
+    filled: bool,
+}
+
+impl PalindromeWindows {
+    /// Return the amount of windows left in [`Self`].
+    pub const fn len(&self) -> usize {
+        if self.filled {
+            // Every palindrome left in the iterator completes one more window.
+            self.iter.len()
+        } else {
+            self.iter.len().saturating_sub(self.size - 1)
+        }
+    }
+
+    /// Return whether [`Self`] has no windows left.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Iterator for PalindromeWindows {
+    type Item = Vec<Palindrome>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.filled {
+            let next = self.iter.next()?;
+            self.window.pop_front();
+            self.window.push_back(next);
+        } else {
+            if self.iter.len() < self.size {
+                return None;
+            }
+            self.window.reserve_exact(self.size);
+            self.window.extend(self.iter.by_ref().take(self.size));
+            self.filled = true;
+        }
+
+        Some(self.window.iter().copied().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl ExactSizeIterator for PalindromeWindows {}
+
+/// An ascending iterator over the union of two [`PalindromeIter`]s.
+///
+/// Created by [`PalindromeIter::merge`].
+pub struct PalindromeMerge {
+    left: PalindromeIter,
+    right: PalindromeIter,
+}
+
+impl PalindromeMerge {
+    /// Return the amount of palindromes left in [`Self`].
+    ///
+    /// **NOTE:** This function is constant time, just like [`PalindromeIter::len`].
+    pub const fn len(&self) -> usize {
+        if self.left.is_empty() || self.right.is_empty() {
+            return self.left.len() + self.right.len();
+        }
+
+        // Palindromes in the overlap of both ranges would otherwise be counted twice.
+        let overlap_from = if self.left.from.0 > self.right.from.0 {
+            self.left.from.0
+        } else {
+            self.right.from.0
+        };
+        let overlap_to = if self.left.to < self.right.to {
+            self.left.to
+        } else {
+            self.right.to
+        };
+        let overlap = if overlap_from < overlap_to {
+            PalindromeIter::len_from_0(overlap_to) - PalindromeIter::len_from_0(overlap_from)
+        } else {
+            0
+        };
+
+        self.left.len() + self.right.len() - overlap
+    }
+
+    /// Return whether [`Self`] has no palindromes left.
+    pub const fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+}
+
+impl Iterator for PalindromeMerge {
+    type Item = Palindrome;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left_empty = self.left.from.0 >= self.left.to;
+        let right_empty = self.right.from.0 >= self.right.to;
+        match (left_empty, right_empty) {
+            (true, true) => None,
+            (false, true) => self.left.next(),
+            (true, false) => self.right.next(),
+            (false, false) => {
+                if self.left.from < self.right.from {
+                    self.left.next()
+                } else if self.right.from < self.left.from {
+                    self.right.next()
+                } else {
+                    // Both yield the same palindrome, so skip it in one of them.
+                    self.right.next();
+                    self.left.next()
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl ExactSizeIterator for PalindromeMerge {}
+
+/// An iterator over the `u64` values of the palindromes in a [`PalindromeIter`].
+///
+/// Created by [`PalindromeIter::values`].
+pub struct PalindromeValues {
+    iter: PalindromeIter,
+}
+
+impl PalindromeValues {
+    /// Return the amount of values left in [`Self`].
+    pub const fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    /// Return whether [`Self`] has no values left.
+    pub const fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+impl Iterator for PalindromeValues {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|palindrome| palindrome.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last().map(|palindrome| palindrome.0)
+    }
+}
+
+impl ExactSizeIterator for PalindromeValues {}
+
+/// An iterator that calls a closure on the `u64` values of the palindromes in a [`PalindromeIter`].
+///
+/// Created by [`PalindromeIter::map_values`].
+pub struct PalindromeMapValues<F> {
+    iter: PalindromeIter,
+    f: F,
+}
+
+impl<F> PalindromeMapValues<F> {
+    /// Return the amount of values left in [`Self`].
+    pub const fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    /// Return whether [`Self`] has no values left.
+    pub const fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+impl<T, F: FnMut(u64) -> T> Iterator for PalindromeMapValues<F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|palindrome| (self.f)(palindrome.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<T, F: FnMut(u64) -> T> ExactSizeIterator for PalindromeMapValues<F> {}
+
+/// An iterator over a [`PalindromeIter`], with the amount of palindromes left after every palindrome.
+///
+/// Created by [`PalindromeIter::with_remaining`].
+pub struct PalindromeWithRemaining {
+    iter: PalindromeIter,
+    remaining: usize,
+}
+
+impl PalindromeWithRemaining {
+    /// Return the amount of palindromes left in [`Self`].
+    pub const fn len(&self) -> usize {
+        self.remaining
+    }
+
+    /// Return whether [`Self`] has no palindromes left.
+    pub const fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl Iterator for PalindromeWithRemaining {
+    type Item = (Palindrome, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let palindrome = self.iter.next()?;
+        self.remaining -= 1;
+        Some((palindrome, self.remaining))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl ExactSizeIterator for PalindromeWithRemaining {}
+
+/// An iterator over a [`PalindromeIter`] where the palindromes are a minimum distance apart in value.
+///
+/// Created by [`PalindromeIter::step_by_value`].
+pub struct PalindromeStepByValue {
+    iter: PalindromeIter,
+    gap: u64,
+    /// The lowest value the next palindrome can have.
+    limit: u64,
+}
+
+impl PalindromeStepByValue {
+    /// Return an upper bound for the amount of palindromes left in [`Self`].
+    ///
+    /// **NOTE:** The bound is the smaller of the amount of palindromes left in the range and
+    /// the amount of `gap`-sized steps that fit between the next and the last palindrome.
+    /// Use [`Self::count`] for the exact amount, which iterates.
+    pub const fn len(&self) -> usize {
+        if self.iter.is_empty() || self.limit > Palindrome::MAX.0 {
+            return 0;
+        }
+
+        let first = if self.limit > self.iter.from.0 {
+            Palindrome::ge(self.limit)
+        } else {
+            self.iter.from
+        };
+        let last = Palindrome::le(self.iter.to - 1);
+        if first.0 > last.0 {
+            return 0;
+        }
+
+        let gap = if self.gap == 0 { 1 } else { self.gap };
+        let steps = ((last.0 - first.0) / gap) as usize + 1;
+        let remaining = self.iter.len();
+        if steps < remaining {
+            steps
+        } else {
+            remaining
+        }
+    }
+
+    /// Return whether [`Self`] has no palindromes left.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Iterator for PalindromeStepByValue {
+    type Item = Palindrome;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let palindrome = self.iter.find_ge(self.limit)?;
+        self.limit = palindrome.0.saturating_add(self.gap);
+        Some(palindrome)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len.min(1), Some(len))
+    }
+}
+
+/// Check whether a number is a palindrome.
+///
+/// With the `num-traits` feature enabled, this is implemented for every
+/// unsigned [`PrimInt`](https://docs.rs/num-traits/latest/num_traits/int/trait.PrimInt.html) (including [`u128`] and [`usize`]).
+pub trait IsPalindrome {
+    /// Return whether `self` is a palindrome.
+    ///
+    /// **NOTE:** Numbers are read without leading zeros, so any number ending in `0`
+    /// (except `0` itself) isn't a palindrome. See [`Palindrome::is_palindrome_fixed_width`]
+    /// for reading numbers with leading zeros.
+    fn is_palindrome(&self) -> bool;
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::PrimInt + num_traits::Unsigned> IsPalindrome for T {
+    fn is_palindrome(&self) -> bool {
+        let zero = T::zero();
+        let ten = T::from(10).unwrap(); // Every unsigned integer fits 10.
+        if *self % ten == zero && *self != zero {
+            return false;
+        }
+
+        let mut x = *self;
+        let mut right_half = zero;
+        while x > right_half {
+            right_half = right_half * ten + x % ten;
+            x = x / ten;
+        }
+
+        x == right_half || x == right_half / ten
+    }
+}
+
+/// `10^exp` for every `exp` where it fits in a [`u64`].
+#[cfg(feature = "simd")]
+const POWERS_OF_TEN: [u64; 20] = {
+    let mut powers = [1; 20];
+    let mut exp = 1;
+    while exp < powers.len() {
+        powers[exp] = powers[exp - 1] * 10;
+        exp += 1;
+    }
+    powers
+};
+
+/// Batched palindrome checks, enabled with the `simd` feature.
+#[cfg(feature = "simd")]
+impl Palindrome {
+    /// Set `out[i]` to whether `values[i]` is a palindrome, for every `i`.
+    /// E.g.: `[121, 120, 7]` sets `out` to `[true, false, true]`.
+    ///
+    /// **NOTE:** The values are checked in fixed-size groups. A branch-free pass first compares
+    /// the first and last digit of every value, so only the few values passing it get the full
+    /// check of [`IsPalindrome::is_palindrome`]. On large, unordered slices this takes roughly
+    /// 40% less time than calling [`IsPalindrome::is_palindrome`] on every value.
+    /// For contiguous ranges, [`PalindromeIter::to_mask`] is much faster still.
+    ///
+    /// **ATTENTION:** Will panic if `values` and `out` have different lengths.
+    pub fn is_palindrome_batch(values: &[u64], out: &mut [bool]) {
+        const LANES: usize = 8;
+        assert_eq!(
+            values.len(),
+            out.len(),
+            "values and out must have the same length"
+        );
+
+        let mut value_chunks = values.chunks_exact(LANES);
+        let mut out_chunks = out.chunks_exact_mut(LANES);
+        for (chunk, out_chunk) in (&mut value_chunks).zip(&mut out_chunks) {
+            // Compare the first and the last digit of every lane without branching or dividing
+            // by a variable, which rules out almost every non-palindrome.
+            let mut candidates = [false; LANES];
+            for lane in 0..LANES {
+                let value = chunk[lane];
+                // The amount of bits gives the amount of digits up to one, since 1233 / 4096 is
+                // just above log10(2).
+                let guess = (((64 - (value | 1).leading_zeros()) * 1233) >> 12) as usize;
+                let exp = guess - ((value | 1) < POWERS_OF_TEN[guess]) as usize;
+                let magnitude = POWERS_OF_TEN[exp];
+                let last = value % 10;
+                candidates[lane] = (value < 10)
+                    | ((last != 0)
+                        & (value >= last.saturating_mul(magnitude))
+                        & (value < (last + 1).saturating_mul(magnitude)));
+            }
+            for lane in 0..LANES {
+                out_chunk[lane] = candidates[lane] && chunk[lane].is_palindrome();
+            }
+        }
+        for (value, out) in value_chunks
+            .remainder()
+            .iter()
+            .zip(out_chunks.into_remainder())
+        {
+            *out = value.is_palindrome();
+        }
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl IsPalindrome for u64 {
+    #[allow(clippy::manual_is_multiple_of)]
+    fn is_palindrome(&self) -> bool {
+        if *self % 10 == 0 && *self != 0 {
+            return false;
+        }
+
+        let mut x = *self;
+        let mut right_half = 0;
+        while x > right_half {
+            right_half = right_half * 10 + x % 10;
+            x /= 10;
+        }
+
+        x == right_half || x == right_half / 10
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl IsPalindrome for u32 {
+    fn is_palindrome(&self) -> bool {
+        (*self as u64).is_palindrome()
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl IsPalindrome for u16 {
+    fn is_palindrome(&self) -> bool {
+        (*self as u64).is_palindrome()
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl IsPalindrome for u8 {
+    fn is_palindrome(&self) -> bool {
+        (*self as u64).is_palindrome()
+    }
+}
+
+impl IsPalindrome for Palindrome {
+    #[inline]
+    fn is_palindrome(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PalindromeIter;
+
+    use super::Palindrome;
+
+    #[test]
+    fn test_palindrome_is_palindrome_in_base() {
+        assert!(Palindrome::is_palindrome_in_base(9, 2)); // 1001
+        assert!(!Palindrome::is_palindrome_in_base(10, 2)); // 1010
+        assert!(Palindrome::is_palindrome_in_base(0, 2));
+        assert!(Palindrome::is_palindrome_in_base(255, 16)); // FF
+        assert!(!Palindrome::is_palindrome_in_base(256, 16)); // 100
+        assert!(Palindrome::is_palindrome_in_base(u64::MAX, 2));
+        assert!(Palindrome::is_palindrome_in_base(u64::MAX, 16));
+        assert!(Palindrome::is_palindrome_in_base(5, 100));
+        for x in 0..10_000u64 {
+            assert_eq!(
+                Palindrome::is_palindrome(x),
+                Palindrome::is_palindrome_in_base(x, 10)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindrome_is_palindrome_in_base_panic_on_base_1() {
+        Palindrome::is_palindrome_in_base(5, 1);
+    }
+
+    #[test]
+    fn test_palindrome_palindromic_bases_nontrivial() {
+        assert_eq!(vec![3, 4, 9], Palindrome::palindromic_bases_nontrivial(10));
+        assert_eq!(vec![2], Palindrome::palindromic_bases_nontrivial(3));
+        assert!(Palindrome::palindromic_bases_nontrivial(0).is_empty());
+        assert!(Palindrome::palindromic_bases_nontrivial(1).is_empty());
+        assert!(Palindrome::palindromic_bases_nontrivial(2).is_empty());
+        let x = u32::MAX as u64; // All ones in every base 2^k where k divides 32.
+        let bases = Palindrome::palindromic_bases_nontrivial(x);
+        for base in [2, 4, 16, 256, 65536, u32::MAX - 1] {
+            assert!(bases.contains(&base), "{base}");
+        }
+        assert!(bases.is_sorted());
+        assert!(bases
+            .iter()
+            .all(|base| Palindrome::is_palindrome_in_base(x, *base)));
+        for x in 0..2_000u64 {
+            let expected: Vec<u32> = (2..=x as u32)
+                .filter(|base| Palindrome::is_palindrome_in_base(x, *base))
+                .collect();
+            assert_eq!(expected, Palindrome::palindromic_bases_nontrivial(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_digits_are_palindrome() {
+        assert!(Palindrome::digits_are_palindrome(&[1, 2, 3, 2, 1]));
+        assert!(Palindrome::digits_are_palindrome(&[4, 4]));
+        assert!(Palindrome::digits_are_palindrome(&[7]));
+        assert!(Palindrome::digits_are_palindrome(&[]));
+        assert!(Palindrome::digits_are_palindrome(&[0, 1, 0]));
+        assert!(Palindrome::digits_are_palindrome(&[200, 13, 200])); // Not digits.
+        assert!(!Palindrome::digits_are_palindrome(&[1, 2, 3]));
+        assert!(!Palindrome::digits_are_palindrome(&[1, 2, 2, 3]));
+        assert!(!Palindrome::digits_are_palindrome(&[1, 0]));
+        for x in 0..10_000u64 {
+            let digits: Vec<u8> = x.to_string().bytes().map(|b| b - b'0').collect();
+            assert_eq!(
+                Palindrome::is_palindrome(x),
+                Palindrome::digits_are_palindrome(&digits)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_base36() {
+        assert!(Palindrome::is_base36_palindrome(1333)); // 111
+        assert!(Palindrome::is_base36_palindrome(35)); // z
+        assert!(Palindrome::is_base36_palindrome(0));
+        assert!(!Palindrome::is_base36_palindrome(36)); // 10
+        assert!(Palindrome::is_base36_palindrome(1295)); // zz
+
+        // Length 1 and 2 in base 36.
+        for x in 0..36 {
+            assert_eq!(x, Palindrome::ge_base36(x));
+        }
+        assert_eq!(37, Palindrome::ge_base36(36)); // 10 -> 11
+        assert_eq!(74, Palindrome::ge_base36(38)); // 12 -> 22
+        assert_eq!(1295, Palindrome::ge_base36(1295)); // zz
+        assert_eq!(1295, Palindrome::ge_base36(1260)); // z0 -> zz
+        assert_eq!(1297, Palindrome::ge_base36(1296)); // 100 -> 101
+
+        let mut expected = 0;
+        for x in 0..200_000 {
+            if x > expected {
+                expected = (x..)
+                    .find(|y| Palindrome::is_base36_palindrome(*y))
+                    .unwrap();
+            }
+            assert_eq!(expected, Palindrome::ge_base36(x), "{x}");
+        }
+
+        // The top of the range.
+        let max_base36 = 18_446_744_073_401_514_579;
+        assert!(Palindrome::is_base36_palindrome(max_base36));
+        assert_eq!(max_base36, Palindrome::ge_base36(max_base36));
+        assert_eq!(max_base36, Palindrome::ge_base36(max_base36 - 1));
+        assert_eq!(max_base36, Palindrome::ge_base36(max_base36 + 1));
+        assert_eq!(max_base36, Palindrome::ge_base36(u64::MAX));
+        assert_eq!(None, Palindrome::ge_in_base(max_base36 + 1, 36));
+        assert_eq!(Some(u64::MAX), Palindrome::ge_in_base(u64::MAX - 1, 2));
+    }
+
+    #[test]
+    fn test_palindrome_closest_radix() {
+        assert_eq!(9, Palindrome::closest_radix(10, 2));
+        assert_eq!(7, Palindrome::closest_radix(6, 2)); // Tie between 5 and 7.
+        assert_eq!(0, Palindrome::closest_radix(0, 2));
+        assert_eq!(0xfeef, Palindrome::closest_radix(0xfef0, 16));
+        assert_eq!(1000, Palindrome::closest_radix(1000, 1001)); // A single digit.
+        assert_eq!(1001, Palindrome::closest_radix(1001, 1000)); // `11` in radix 1000.
+        assert_eq!(u64::MAX, Palindrome::closest_radix(u64::MAX, 2));
+        assert_eq!(u64::MAX, Palindrome::closest_radix(u64::MAX, 16));
+        // `u64::MAX` is `1 2 0` in radix `u32::MAX`, and `1 2 1` doesn't fit,
+        // so `1 1 1` is the closest.
+        let radix = u32::MAX as u64;
+        assert_eq!(
+            radix * radix + radix + 1,
+            Palindrome::closest_radix(u64::MAX, u32::MAX)
+        );
+
+        for radix in [2, 3, 7, 10, 16, 36, 100] {
+            for x in 0..3000u64 {
+                let le = (0..=x)
+                    .rev()
+                    .find(|y| Palindrome::is_palindrome_in_base(*y, radix))
+                    .unwrap();
+                let ge = (x..)
+                    .find(|y| Palindrome::is_palindrome_in_base(*y, radix))
+                    .unwrap();
+                let expected = if ge - x <= x - le { ge } else { le };
+                assert_eq!(
+                    expected,
+                    Palindrome::closest_radix(x, radix),
+                    "{x} in {radix}"
+                );
+            }
+        }
+        let near_max = Palindrome::MAX.0 - 100..=Palindrome::MAX.0 + 100;
+        for x in (0..100_000)
+            .chain(near_max)
+            .chain(u64::MAX - 100..=u64::MAX)
+        {
+            if x <= Palindrome::MAX.0 {
+                assert_eq!(
+                    Palindrome::closest(x),
+                    Palindrome::closest_radix(x, 10),
+                    "{x}"
+                );
+            } else {
+                assert_eq!(Palindrome::MAX, Palindrome::closest_radix(x, 10), "{x}");
+            }
+        }
+
+        // No base-36 palindrome above the largest one fits, so the largest one is the closest.
+        let max_base36 = 18_446_744_073_401_514_579;
+        assert_eq!(max_base36, Palindrome::closest_radix(u64::MAX, 36));
+        assert_eq!(max_base36, Palindrome::closest_radix(max_base36 + 1, 36));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindrome_closest_radix_panic_on_radix_1() {
+        Palindrome::closest_radix(5, 1);
+    }
+
+    #[test]
+    fn test_palindrome_from_parts() {
+        assert_eq!(Some(Palindrome(12321)), Palindrome::from_parts(12, Some(3)));
+        assert_eq!(Some(Palindrome(1221)), Palindrome::from_parts(12, None));
+        assert_eq!(Some(Palindrome(101)), Palindrome::from_parts(1, Some(0)));
+        assert_eq!(Some(Palindrome(11)), Palindrome::from_parts(1, None));
+        assert_eq!(Some(Palindrome(1001)), Palindrome::from_parts(10, None));
+        assert_eq!(None, Palindrome::from_parts(0, None));
+        assert_eq!(None, Palindrome::from_parts(0, Some(5)));
+        assert_eq!(None, Palindrome::from_parts(12, Some(10)));
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::from_parts(1_844_674_406, None)
+        );
+        assert_eq!(None, Palindrome::from_parts(1_844_674_407, None));
+        assert_eq!(
+            Some(Palindrome::largest_with_digits(19)),
+            Palindrome::from_parts(999_999_999, Some(9))
+        );
+        assert_eq!(None, Palindrome::from_parts(1_000_000_000, Some(0)));
+        assert_eq!(None, Palindrome::from_parts(u64::MAX, None));
+        assert_eq!(None, Palindrome::from_parts(u64::MAX / 10, Some(9)));
+
+        for pal in PalindromeIter::from(10u64, 1_000_000u64) {
+            let digits = pal.to_string();
+            let half = &digits[..digits.len() / 2];
+            let center = (digits.len() % 2 == 1).then(|| digits.as_bytes()[half.len()] - b'0');
+            assert_eq!(
+                Some(pal),
+                Palindrome::from_parts(half.parse().unwrap(), center),
+                "{pal}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_from_half_digits_radix() {
+        assert_eq!(
+            Some(9),
+            Palindrome::from_half_digits_radix(&[1, 0], false, 2)
+        );
+        assert_eq!(
+            Some(5),
+            Palindrome::from_half_digits_radix(&[1, 0], true, 2)
+        );
+        assert_eq!(
+            Some(12321),
+            Palindrome::from_half_digits_radix(&[1, 2, 3], true, 10)
+        );
+        assert_eq!(
+            Some(123321),
+            Palindrome::from_half_digits_radix(&[1, 2, 3], false, 10)
+        );
+        assert_eq!(
+            Some(0xABBA),
+            Palindrome::from_half_digits_radix(&[10, 11], false, 16)
+        );
+        assert_eq!(Some(0), Palindrome::from_half_digits_radix(&[0], true, 10));
+        assert_eq!(None, Palindrome::from_half_digits_radix(&[0], false, 10));
+        assert_eq!(None, Palindrome::from_half_digits_radix(&[0, 1], true, 10));
+        assert_eq!(None, Palindrome::from_half_digits_radix(&[], true, 10));
+        assert_eq!(None, Palindrome::from_half_digits_radix(&[1, 2], true, 2));
+        assert_eq!(
+            Some(u64::MAX),
+            Palindrome::from_half_digits_radix(&[1; 32], false, 2)
+        );
+        assert_eq!(None, Palindrome::from_half_digits_radix(&[1; 33], true, 2));
+        assert_eq!(
+            Some(Palindrome::MAX.0),
+            Palindrome::from_half_digits_radix(&[1, 8, 4, 4, 6, 7, 4, 4, 0, 6], false, 10)
+        );
+        assert_eq!(
+            None,
+            Palindrome::from_half_digits_radix(&[1, 8, 4, 4, 6, 7, 4, 4, 0, 7], false, 10)
+        );
+
+        let mut binary = Vec::new();
+        for length in 1..=10 {
+            let half_length = (length + 1) / 2;
+            for pattern in 1u64 << (half_length - 1)..1 << half_length {
+                let half: Vec<u8> = (0..half_length)
+                    .rev()
+                    .map(|bit| (pattern >> bit & 1) as u8)
+                    .collect();
+                binary.push(Palindrome::from_half_digits_radix(&half, length % 2 == 1, 2).unwrap());
+            }
+        }
+        binary.sort();
+        let expected: Vec<u64> = (1..1 << 10)
+            .filter(|x| Palindrome::is_palindrome_in_base(*x, 2))
+            .collect();
+        assert_eq!(expected, binary);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be at least 2")]
+    fn test_palindrome_from_half_digits_radix_panic_on_radix_1() {
+        Palindrome::from_half_digits_radix(&[1], true, 1);
+    }
+
+    #[test]
+    fn test_palindrome_is_palindrome_up_to_base() {
+        assert!(Palindrome::is_palindrome_up_to_base(3, 2)); // 11
+        assert!(Palindrome::is_palindrome_up_to_base(5, 2)); // 101
+        assert!(!Palindrome::is_palindrome_up_to_base(5, 3));
+        assert!(Palindrome::is_palindrome_up_to_base(6, 1)); // No bases to check.
+                                                             // Single digits are palindromes in every base above them.
+        assert!(Palindrome::is_palindrome_up_to_base(1, u32::MAX));
+        assert!(Palindrome::is_palindrome_up_to_base(0, u32::MAX));
+        assert!(!Palindrome::is_palindrome_up_to_base(2, u32::MAX)); // 10 in base 2.
+        for x in 0..200u64 {
+            let expected = (2..=10).all(|base| Palindrome::is_palindrome_in_base(x, base));
+            assert_eq!(expected, Palindrome::is_palindrome_up_to_base(x, 10), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_abs_diff() {
+        let pal = Palindrome(121);
+        assert_eq!(21, pal.abs_diff(100u64));
+        assert_eq!(79, pal.abs_diff(200u64));
+        assert_eq!(0, pal.abs_diff(121u64));
+        assert_eq!(121, pal.abs_diff(0u64));
+        assert_eq!(u64::MAX - 121, pal.abs_diff(u64::MAX));
+        assert_eq!(10, pal.abs_diff(Palindrome(111)));
+        assert_eq!(10, Palindrome(111).abs_diff(pal));
+        assert_eq!(Palindrome::MAX.0, Palindrome::MIN.abs_diff(Palindrome::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_checked_add_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(Some(200), pal.checked_add_u64(79));
+        assert_eq!(
+            Some(u64::MAX),
+            Palindrome::MAX.checked_add_u64(u64::MAX - Palindrome::MAX.0)
+        );
+        assert_eq!(None, Palindrome::MAX.checked_add_u64(u64::MAX));
+        assert_eq!(Some(5), Palindrome::MIN.checked_add_u64(5));
+    }
+
+    #[test]
+    fn test_palindrome_checked_mul_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(Some(363), pal.checked_mul_u64(3));
+        assert_eq!(Some(0), pal.checked_mul_u64(0));
+        assert_eq!(Some(Palindrome::MAX.0), Palindrome::MAX.checked_mul_u64(1));
+        assert_eq!(None, Palindrome::MAX.checked_mul_u64(2));
+        assert_eq!(Some(0), Palindrome::MIN.checked_mul_u64(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_mul_u128() {
+        let pal = Palindrome(121);
+        assert_eq!(363, pal.mul_u128(3));
+        assert_eq!(0, pal.mul_u128(0));
+        assert_eq!(0, Palindrome::MIN.mul_u128(u64::MAX));
+        assert_eq!(Palindrome::MAX.0 as u128 * 2, Palindrome::MAX.mul_u128(2));
+        assert_eq!(
+            Palindrome::MAX.0 as u128 * u64::MAX as u128,
+            Palindrome::MAX.mul_u128(u64::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "strict-arithmetic", debug_assertions))]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_palindrome_add_overflow_panics() {
+        let _ = Palindrome::MAX + Palindrome::MAX;
+    }
+
+    #[test]
+    #[cfg(any(feature = "strict-arithmetic", debug_assertions))]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_palindrome_sub_overflow_panics() {
+        let mut x = 5u64;
+        x -= Palindrome(6);
+        let _ = x;
+    }
+
+    #[test]
+    #[cfg(any(feature = "strict-arithmetic", debug_assertions))]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_palindrome_mul_overflow_panics() {
+        let _ = 2 * Palindrome::MAX;
+    }
+
+    #[test]
+    #[cfg(any(feature = "strict-arithmetic", debug_assertions))]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_palindrome_shl_overflow_panics() {
+        let _ = Palindrome(1) << 64;
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "strict-arithmetic", debug_assertions)))]
+    fn test_palindrome_overflow_wraps() {
+        assert_eq!(
+            Palindrome::MAX.0.wrapping_add(Palindrome::MAX.0),
+            Palindrome::MAX + Palindrome::MAX
+        );
+        assert_eq!(u64::MAX, 5u64 - Palindrome(6));
+        assert_eq!(Palindrome::MAX.0.wrapping_mul(2), 2 * Palindrome::MAX);
+        assert_eq!(1, Palindrome(1) << 64); // The shift is masked to `64 % 64`.
+    }
+
+    #[test]
+    fn test_palindrome_checked_sub_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(Some(100), pal.checked_sub_u64(21));
+        assert_eq!(Some(0), pal.checked_sub_u64(121));
+        assert_eq!(None, pal.checked_sub_u64(122));
+        assert_eq!(None, Palindrome::MIN.checked_sub_u64(1));
+        assert_eq!(Some(Palindrome::MAX.0), Palindrome::MAX.checked_sub_u64(0));
+    }
+
+    #[test]
+    fn test_palindrome_saturating_sub_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(100, pal.saturating_sub_u64(21));
+        assert_eq!(0, pal.saturating_sub_u64(121));
+        assert_eq!(0, pal.saturating_sub_u64(u64::MAX));
+        assert_eq!(0, Palindrome::MIN.saturating_sub_u64(1));
+    }
+
+    #[test]
+    fn test_palindrome_saturating_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(200, pal.saturating_add_u64(79));
+        assert_eq!(u64::MAX, Palindrome::MAX.saturating_add_u64(u64::MAX));
+        assert_eq!(363, pal.saturating_mul_u64(3));
+        assert_eq!(u64::MAX, Palindrome::MAX.saturating_mul_u64(2));
+        assert_eq!(0, Palindrome::MIN.saturating_mul_u64(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_wrapping_u64() {
+        let pal = Palindrome(121);
+        assert_eq!(200, pal.wrapping_add_u64(79));
+        assert_eq!(120, pal.wrapping_add_u64(u64::MAX));
+        assert_eq!(100, pal.wrapping_sub_u64(21));
+        assert_eq!(u64::MAX, Palindrome::MIN.wrapping_sub_u64(1));
+        assert_eq!(363, pal.wrapping_mul_u64(3));
+        assert_eq!(
+            Palindrome::MAX.0.wrapping_mul(2),
+            Palindrome::MAX.wrapping_mul_u64(2)
+        );
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        use crate::IsPalindrome;
+
+        assert!(0u8.is_palindrome());
+        assert!(9u8.is_palindrome());
+        assert!(!10u8.is_palindrome());
+        assert!(252u8.is_palindrome());
+        assert!(!255u8.is_palindrome());
+        assert!(65456u16.is_palindrome());
+        assert!(!65535u16.is_palindrome());
+        assert!(4294884924u32.is_palindrome());
+        assert!(!4294967295u32.is_palindrome());
+        assert!(Palindrome::MAX.0.is_palindrome());
+        assert!(!u64::MAX.is_palindrome());
+        assert!(!1000u64.is_palindrome());
+    }
+
+    #[test]
+    fn test_is_palindrome_trailing_zeros() {
+        use crate::IsPalindrome;
+
+        assert!(0u8.is_palindrome());
+        assert!(0u16.is_palindrome());
+        assert!(0u32.is_palindrome());
+        assert!(0u64.is_palindrome());
+        assert!(Palindrome::is_palindrome(0));
+        for x in [10u64, 100, 110, 1000, 1_000_000, 10_000_000_000_000_000_000] {
+            assert!(!x.is_palindrome(), "{x}");
+            assert!(!Palindrome::is_palindrome(x), "{x}");
+        }
+        assert!(!10u8.is_palindrome());
+        assert!(!100u8.is_palindrome());
+        assert!(!10_000u16.is_palindrome());
+        assert!(!1_000_000_000u32.is_palindrome());
+    }
+
+    #[test]
+    fn test_is_palindrome_odd_and_even_length() {
+        use crate::IsPalindrome;
+
+        // Odd length palindromes end with `x == right_half / 10`.
+        for x in [1u64, 121, 12321, 1234321, 9_000_000_009_000_000_009] {
+            assert!(x.is_palindrome(), "{x}");
+            assert!(Palindrome::is_palindrome(x), "{x}");
+        }
+        // Even length palindromes end with `x == right_half`.
+        for x in [11u64, 1221, 123321, 9_000_000_000_000_000_009] {
+            assert!(x.is_palindrome(), "{x}");
+            assert!(Palindrome::is_palindrome(x), "{x}");
+        }
+        for x in [12u64, 1231, 12320, 12331, 123421, 1_000_000_001_000_000_000] {
+            assert!(!x.is_palindrome(), "{x}");
+            assert!(!Palindrome::is_palindrome(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_is_palindrome_matches_string_reversal() {
+        use crate::IsPalindrome;
+
+        for x in 0..100_000u64 {
+            let digits = x.to_string();
+            let reversed: String = digits.chars().rev().collect();
+            assert_eq!(digits == reversed, x.is_palindrome(), "{x}");
+            assert_eq!(digits == reversed, Palindrome::is_palindrome(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_is_near_palindrome() {
+        use crate::IsPalindrome;
+
+        assert!(Palindrome::is_near_palindrome(12320, 1));
+        assert!(!Palindrome::is_near_palindrome(12320, 0));
+        assert!(!Palindrome::is_near_palindrome(12340, 1));
+        assert!(Palindrome::is_near_palindrome(12340, 2));
+        assert!(Palindrome::is_near_palindrome(12321, 0));
+        assert!(Palindrome::is_near_palindrome(0, 0));
+        assert!(Palindrome::is_near_palindrome(10, 1));
+        assert!(!Palindrome::is_near_palindrome(10, 0));
+        assert!(Palindrome::is_near_palindrome(1000, 1));
+        assert!(Palindrome::is_near_palindrome(123456, 3));
+        assert!(!Palindrome::is_near_palindrome(123456, 2));
+        assert!(Palindrome::is_near_palindrome(u64::MAX, 10));
+        assert!(!Palindrome::is_near_palindrome(u64::MAX, 2));
+        assert!(Palindrome::is_near_palindrome(Palindrome::MAX.0, 0));
+        for x in 0..100_000u64 {
+            let digits = x.to_string().into_bytes();
+            let edits = (0..digits.len() / 2)
+                .filter(|&i| digits[i] != digits[digits.len() - 1 - i])
+                .count() as u32;
+            assert_eq!(
+                x.is_palindrome(),
+                Palindrome::is_near_palindrome(x, 0),
+                "{x}"
+            );
+            assert!(Palindrome::is_near_palindrome(x, edits), "{x}");
+            assert!(
+                edits == 0 || !Palindrome::is_near_palindrome(x, edits - 1),
+                "{x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_nine_complement() {
+        assert!(Palindrome::is_nine_complement(1368));
+        assert!(!Palindrome::is_nine_complement(1358));
+        assert!(Palindrome::is_nine_complement(45));
+        assert!(Palindrome::is_nine_complement(90));
+        assert!(!Palindrome::is_nine_complement(9));
+        assert!(!Palindrome::is_nine_complement(0));
+        assert!(!Palindrome::is_nine_complement(12345));
+        assert!(Palindrome::is_nine_complement(18));
+        assert!(Palindrome::is_nine_complement(81));
+        assert!(!Palindrome::is_nine_complement(1234567887654321));
+        assert!(Palindrome::is_nine_complement(1234567812345678));
+        assert!(Palindrome::is_nine_complement(10000000009999999998));
+        assert!(!Palindrome::is_nine_complement(u64::MAX));
+        assert!(!Palindrome::is_nine_complement(Palindrome::closest(1368).0));
+        for x in 0..100_000u64 {
+            let digits = x.to_string().into_bytes();
+            let expected = digits.len() % 2 == 0
+                && (0..digits.len() / 2)
+                    .all(|i| digits[i] - b'0' + digits[digits.len() - 1 - i] - b'0' == 9);
+            assert_eq!(expected, Palindrome::is_nine_complement(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_is_palindrome_fixed_width() {
+        assert!(Palindrome::is_palindrome_fixed_width(110, 4));
+        assert!(!Palindrome::is_palindrome_fixed_width(110, 3));
+        assert!(!Palindrome::is_palindrome_fixed_width(110, 5));
+        assert!(!Palindrome::is_palindrome_fixed_width(110, 2)); // Doesn't fit.
+        assert!(Palindrome::is_palindrome_fixed_width(121, 3));
+        assert!(!Palindrome::is_palindrome_fixed_width(121, 4));
+        assert!(Palindrome::is_palindrome_fixed_width(2200, 6));
+        assert!(!Palindrome::is_palindrome_fixed_width(1200, 6));
+        assert!(!Palindrome::is_palindrome_fixed_width(2200, 5));
+        assert!(Palindrome::is_palindrome_fixed_width(0, 1));
+        assert!(Palindrome::is_palindrome_fixed_width(0, 5));
+        assert!(!Palindrome::is_palindrome_fixed_width(0, 0));
+        assert!(Palindrome::is_palindrome_fixed_width(10, 3));
+        assert!(!Palindrome::is_palindrome_fixed_width(10, 2));
+        assert!(Palindrome::is_palindrome_fixed_width(100, 5));
+        assert!(Palindrome::is_palindrome_fixed_width(1000, 7));
+        assert!(!Palindrome::is_palindrome_fixed_width(u64::MAX, 40));
+        assert!(!Palindrome::is_palindrome_fixed_width(u64::MAX, u32::MAX));
+        assert!(Palindrome::is_palindrome_fixed_width(Palindrome::MAX.0, 20));
+        assert!(!Palindrome::is_palindrome_fixed_width(
+            Palindrome::MAX.0,
+            21
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn test_is_palindrome_num_traits() {
+        use crate::IsPalindrome;
+
+        assert!(0u128.is_palindrome());
+        assert!(!10u128.is_palindrome());
+        assert!(340282366920938463454364839029663282043u128.is_palindrome());
+        assert!(123456789987654321123456789987654321u128.is_palindrome());
+        assert!(!u128::MAX.is_palindrome());
+        assert!(12321usize.is_palindrome());
+        assert!(!12345usize.is_palindrome());
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    fn test_palindrome_is_palindrome_batch() {
+        use crate::IsPalindrome;
+
+        let mut values: Vec<u64> = (0..10_000).collect();
+        for exp in 1..=19 {
+            let power = 10u64.pow(exp);
+            values.extend([power - 1, power, power + 1, power / 10 * 9 + 9]);
+        }
+        values.extend([
+            Palindrome::MAX.0,
+            Palindrome::MAX.0 + 1,
+            u64::MAX - 1,
+            u64::MAX,
+        ]);
+        values.extend([9_000_000_000_000_000_009, 18_000_000_000_000_000_081, 12321]);
+        // Every length of remainder after the groups.
+        for len in values.len() - 9..=values.len() {
+            let mut out = vec![false; len];
+            Palindrome::is_palindrome_batch(&values[..len], &mut out);
+            for (value, is_palindrome) in values.iter().zip(out) {
+                assert_eq!(value.is_palindrome(), is_palindrome, "{value}");
+            }
+        }
+
+        let mut out = [];
+        Palindrome::is_palindrome_batch(&[], &mut out);
+        let mut out = [false; 3];
+        Palindrome::is_palindrome_batch(&[121, 120, 7], &mut out);
+        assert_eq!([true, false, true], out);
+    }
+
+    #[test]
+    #[cfg(feature = "simd")]
+    #[should_panic]
+    fn test_palindrome_is_palindrome_batch_length_mismatch() {
+        Palindrome::is_palindrome_batch(&[1, 2], &mut [false]);
+    }
+
+    #[test]
+    fn test_palindrome_into_integers() {
+        assert_eq!(Palindrome::MAX.0 as u128, u128::from(Palindrome::MAX));
+        assert_eq!(Palindrome::MAX.0 as i128, i128::from(Palindrome::MAX));
+        assert_eq!(121u128, u128::from(&Palindrome(121)));
+        assert_eq!(-121i128, -i128::from(&Palindrome(121)));
+
+        assert_eq!(Ok(121u8), u8::try_from(Palindrome(121)));
+        assert!(u8::try_from(Palindrome(323)).is_err());
+        assert!(i8::try_from(Palindrome(131)).is_err());
+        assert_eq!(Ok(65456u16), u16::try_from(Palindrome(65456)));
+        assert!(i16::try_from(Palindrome(65456)).is_err());
+        assert_eq!(Ok(4294884924u32), u32::try_from(Palindrome(4294884924)));
+        assert!(u32::try_from(Palindrome::MAX).is_err());
+        assert!(i32::try_from(Palindrome(4294884924)).is_err());
+        assert_eq!(
+            Ok(9_000_000_000_000_000_009i64),
+            i64::try_from(Palindrome(9_000_000_000_000_000_009))
+        );
+        assert!(i64::try_from(Palindrome::MAX).is_err());
+        assert_eq!(Ok(12321usize), usize::try_from(Palindrome(12321)));
+        assert_eq!(Ok(12321isize), isize::try_from(Palindrome(12321)));
+    }
+
+    #[test]
+    fn test_palindrome_assign_usize() {
+        let mut pal = Palindrome(121);
+        pal += 2;
+        assert_eq!(141, pal);
+        pal -= 2;
+        assert_eq!(121, pal);
+        pal += 0;
+        assert_eq!(121, pal);
+        pal += &8;
+        assert_eq!(202, pal);
+        pal -= &1;
+        assert_eq!(191, pal);
+
+        let mut pal = Palindrome(9);
+        pal += 1;
+        assert_eq!(11, pal);
+        pal -= 1;
+        assert_eq!(9, pal);
+
+        let mut pal = Palindrome::MAX.previous();
+        pal += 1;
+        assert_eq!(Palindrome::MAX, pal);
+        pal += usize::MAX;
+        assert_eq!(Palindrome::MAX, pal);
+        let mut pal = Palindrome(11);
+        pal -= 100;
+        assert_eq!(Palindrome::MIN, pal);
+
+        let mut pal = Palindrome::MIN;
+        PalindromeIter::first_n(1_000).skip(1).for_each(|expected| {
+            pal += 1;
+            assert_eq!(expected, pal);
+        });
+    }
+
+    #[test]
+    fn test_palindrome_ops_palindrome() {
+        let a = Palindrome(505);
+        let b = Palindrome(22);
+        assert_eq!(527, a + b);
+        assert_eq!(483, a - b);
+        assert_eq!(11110, a * b);
+        assert_eq!(22, a / b);
+        assert_eq!(21, a % b);
+    }
+
+    #[test]
+    fn test_palindrome_const_table() {
+        const TABLE: [Palindrome; 256] = {
+            let mut table = [Palindrome::MIN; 256];
+            let mut idx = 0;
+            while idx < table.len() {
+                let x = idx as u64 * 72_057_594_037_927_936; // u64::MAX / 256 steps.
+                table[idx] = match idx % 3 {
+                    0 => Palindrome::closest(x),
+                    1 => Palindrome::le(x),
+                    _ => Palindrome::ge(x),
+                };
+                idx += 1;
+            }
+            table
+        };
+
+        for (idx, pal) in TABLE.iter().enumerate() {
+            let x = idx as u64 * 72_057_594_037_927_936;
+            let expected = match idx % 3 {
+                0 => Palindrome::closest(x),
+                1 => Palindrome::le(x),
+                _ => Palindrome::ge(x),
+            };
+            assert_eq!(expected, *pal);
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_detailed() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            (Palindrome(11), Ordering::Equal),
+            Palindrome::closest_detailed(10)
+        );
+        assert_eq!(
+            (Palindrome(121), Ordering::Equal),
+            Palindrome::closest_detailed(121)
+        );
+        assert_eq!(
+            (Palindrome(121), Ordering::Less),
+            Palindrome::closest_detailed(125)
+        );
+        assert_eq!(
+            (Palindrome(131), Ordering::Greater),
+            Palindrome::closest_detailed(127)
+        );
+        assert_eq!(
+            (Palindrome(131), Ordering::Equal),
+            Palindrome::closest_detailed(126)
+        );
+        assert_eq!(
+            (Palindrome(0), Ordering::Equal),
+            Palindrome::closest_detailed(0)
+        );
+        assert_eq!(
+            (Palindrome::MAX, Ordering::Equal),
+            Palindrome::closest_detailed(Palindrome::MAX.0)
+        );
+        assert_eq!(
+            (Palindrome::MAX, Ordering::Less),
+            Palindrome::closest_detailed(Palindrome::MAX.0 + 1)
+        );
+        assert_eq!(
+            (Palindrome::MAX, Ordering::Less),
+            Palindrome::closest_detailed(u64::MAX)
+        );
+        for x in 0..100_000 {
+            let (palindrome, ordering) = Palindrome::closest_detailed(x);
+            assert_eq!(Palindrome::closest(x), palindrome);
+            let below = x - Palindrome::le(x).0;
+            let above = Palindrome::ge(x).0 - x;
+            assert_eq!(below.cmp(&above), ordering, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_by_digit_distance() {
+        assert_eq!(
+            Palindrome(12321),
+            Palindrome::closest_by_digit_distance(12399)
+        );
+        assert_eq!(Palindrome(12421), Palindrome::closest(12399));
+        assert_eq!(
+            Palindrome(19991),
+            Palindrome::closest_by_digit_distance(19999)
+        );
+        assert_eq!(Palindrome(20002), Palindrome::closest(19999));
+        assert_eq!(Palindrome(11), Palindrome::closest_by_digit_distance(10));
+        assert_eq!(Palindrome(99), Palindrome::closest_by_digit_distance(98));
+        assert_eq!(
+            Palindrome(1001),
+            Palindrome::closest_by_digit_distance(1000)
+        );
+        assert_eq!(Palindrome(5), Palindrome::closest_by_digit_distance(5));
+        assert_eq!(Palindrome(0), Palindrome::closest_by_digit_distance(0));
+        assert_eq!(Palindrome(121), Palindrome::closest_by_digit_distance(121));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::closest_by_digit_distance(Palindrome::MAX.0)
+        );
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::closest_by_digit_distance(u64::MAX)
+        );
+        for x in 0..100_000u64 {
+            let palindrome = Palindrome::closest_by_digit_distance(x);
+            let (x, palindrome) = (x.to_string(), palindrome.to_string());
+            assert_eq!(x.len(), palindrome.len());
+            let differing = x
+                .bytes()
+                .zip(palindrome.bytes())
+                .filter(|(a, b)| a != b)
+                .count();
+            let mirrored_pairs_differing = x
+                .bytes()
+                .zip(x.bytes().rev())
+                .take(x.len() / 2)
+                .filter(|(a, b)| a != b)
+                .count();
+            assert_eq!(mirrored_pairs_differing, differing, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_ratio() {
+        assert_eq!(
+            Some(Palindrome(333)),
+            Palindrome::closest_ratio(1, 3, 1_000)
+        );
+        assert_eq!(
+            Some(Palindrome(505)),
+            Palindrome::closest_ratio(1, 2, 1_000)
+        );
+        assert_eq!(
+            Some(Palindrome(1001)), // Tie, so the higher one.
+            Palindrome::closest_ratio(1, 1, 1_000)
+        );
+        assert_eq!(
+            Some(Palindrome::MIN),
+            Palindrome::closest_ratio(0, 7, 1_000)
+        );
+        assert_eq!(None, Palindrome::closest_ratio(1, 0, 1_000));
+        assert_eq!(None, Palindrome::closest_ratio(0, 0, 0));
+        assert_eq!(
+            Some(Palindrome::closest(u64::MAX / 2)),
+            Palindrome::closest_ratio(u64::MAX, u64::MAX, u64::MAX / 2)
+        );
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::closest_ratio(u64::MAX, 1, u64::MAX)
+        );
+        for numerator in 0..=50u64 {
+            let x = 12_345 * numerator / 50;
+            assert_eq!(
+                Some(Palindrome::closest(x)),
+                Palindrome::closest_ratio(numerator, 50, 12_345)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_with_neighbors() {
+        assert_eq!(
+            (Palindrome(111), Palindrome(121), Palindrome(131)),
+            Palindrome::closest_with_neighbors(120)
+        );
+        assert_eq!(
+            (Palindrome(9), Palindrome(11), Palindrome(22)),
+            Palindrome::closest_with_neighbors(10)
+        );
+        assert_eq!(
+            (Palindrome(99), Palindrome(101), Palindrome(111)),
+            Palindrome::closest_with_neighbors(100)
+        );
+        assert_eq!(
+            (Palindrome::MIN, Palindrome::MIN, Palindrome(1)),
+            Palindrome::closest_with_neighbors(0)
+        );
+        let below_max = Palindrome::MAX.previous();
+        assert_eq!(
+            (below_max, Palindrome::MAX, Palindrome::MAX),
+            Palindrome::closest_with_neighbors(u64::MAX)
+        );
+        assert_eq!(
+            (below_max.previous(), below_max, Palindrome::MAX),
+            Palindrome::closest_with_neighbors(below_max.0)
+        );
+
+        let all: Vec<Palindrome> = PalindromeIter::from(0u64, 20_000u64).collect();
+        for x in 1..10_000 {
+            let (below, closest, above) = Palindrome::closest_with_neighbors(x);
+            let idx = all.partition_point(|pal| pal.0 < closest.0);
+            assert_eq!(Palindrome::closest(x), closest, "{x}");
+            assert_eq!(all[idx.saturating_sub(1)], below, "{x}");
+            assert_eq!(all[idx + 1], above, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_near() {
+        let pal = Palindrome(121);
+        assert_eq!(141, Palindrome::closest_near(pal, 500, 2));
+        assert_eq!(101, Palindrome::closest_near(pal, 0, 2));
+        assert_eq!(121, Palindrome::closest_near(pal, 121, 0));
+        assert_eq!(121, Palindrome::closest_near(pal, u64::MAX, 0));
+        assert_eq!(131, Palindrome::closest_near(pal, 126, 1)); // Tie.
+        assert_eq!(Palindrome::MIN, Palindrome::closest_near(pal, 0, 1_000));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::closest_near(Palindrome::MAX, u64::MAX, usize::MAX)
+        );
+        assert_eq!(
+            Palindrome::MAX.previous(),
+            Palindrome::closest_near(Palindrome::MAX, 0, 1)
+        );
+
+        let all: Vec<_> = PalindromeIter::first_n(300).collect();
+        for (n, pal) in all.iter().enumerate().step_by(7) {
+            for k in [0, 1, 5, 50] {
+                let window = &all[n.saturating_sub(k)..all.len().min(n + k + 1)];
+                for x in (0..12_000).step_by(37) {
+                    assert_eq!(
+                        Palindrome::closest_in(x, window),
+                        Some(&Palindrome::closest_near(*pal, x, k)),
+                        "p={pal}, x={x}, k={k}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_in() {
+        let set = [Palindrome(11), Palindrome(121), Palindrome(141)];
+        assert_eq!(Some(&Palindrome(121)), Palindrome::closest_in(130, &set));
+        assert_eq!(Some(&Palindrome(141)), Palindrome::closest_in(131, &set)); // Tie.
+        assert_eq!(Some(&Palindrome(141)), Palindrome::closest_in(132, &set));
+        assert_eq!(Some(&Palindrome(11)), Palindrome::closest_in(0, &set));
+        assert_eq!(Some(&Palindrome(121)), Palindrome::closest_in(121, &set));
+        assert_eq!(
+            Some(&Palindrome(141)),
+            Palindrome::closest_in(u64::MAX, &set)
+        );
+        assert_eq!(None, Palindrome::closest_in(130, &[]));
+        assert_eq!(
+            Some(&Palindrome(11)),
+            Palindrome::closest_in(u64::MAX, &[Palindrome(11)])
+        );
+
+        let all: Vec<_> = PalindromeIter::from(0u64, 100_000u64).collect();
+        for x in 0..100_000 {
+            assert_eq!(
+                Palindrome::closest(x),
+                *Palindrome::closest_in(x, &all).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest() {
+        assert_eq!(11, Palindrome::closest(10));
+        assert_eq!(38783, Palindrome::closest(38794));
+        assert_eq!(38783, Palindrome::closest(38832));
+        assert_eq!(38883, Palindrome::closest(38833));
+        assert_eq!(943858349, Palindrome::closest(943854534));
+        assert_eq!(Palindrome::MAX, Palindrome::closest(Palindrome::MAX.0));
+        assert_eq!(Palindrome::MAX, Palindrome::closest(Palindrome::MAX.0 + 1));
+        assert_eq!(Palindrome::MAX, Palindrome::closest(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_same_basin() {
+        assert!(Palindrome::same_basin(17, 27));
+        assert!(!Palindrome::same_basin(16, 17));
+        assert!(!Palindrome::same_basin(10, 9)); // 10 is a tie and goes to 11.
+        assert!(Palindrome::same_basin(10, 11));
+        assert!(Palindrome::same_basin(Palindrome::MAX.0, u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_iterate_closest() {
+        assert_eq!(
+            vec![Palindrome(7), Palindrome(8), Palindrome(9), Palindrome(11)],
+            Palindrome::iterate_closest(7, 5)
+        );
+        assert_eq!(
+            vec![Palindrome(7), Palindrome(8)],
+            Palindrome::iterate_closest(7, 1)
+        );
+        assert_eq!(vec![Palindrome(7)], Palindrome::iterate_closest(7, 0));
+        assert_eq!(11, Palindrome::iterate_closest(0, usize::MAX).len()); // 0 to 9, then 11.
+        assert_eq!(vec![Palindrome(121)], Palindrome::iterate_closest(120, 100));
+        assert_eq!(
+            vec![Palindrome(99), Palindrome(101)],
+            Palindrome::iterate_closest(99, 5)
+        );
+        assert_eq!(
+            vec![Palindrome(999), Palindrome(1001)],
+            Palindrome::iterate_closest(999, 5)
+        );
+        assert_eq!(
+            vec![
+                Palindrome(9_999_999_999_999_999_999),
+                Palindrome(10_000_000_000_000_000_001)
+            ],
+            Palindrome::iterate_closest(9_999_999_999_999_999_999, 5)
+        );
+        // Only the palindromes made of `9`s aren't fixed points.
+        for pal in PalindromeIter::from(11u64, 1_000_000u64) {
+            let is_fixed_point = Palindrome::iterate_closest(pal.0, 1) == vec![pal];
+            assert_eq!(
+                !(pal.is_repdigit() && pal.0 % 10 == 9),
+                is_fixed_point,
+                "{pal}"
+            );
+        }
+        assert_eq!(
+            vec![Palindrome::MAX],
+            Palindrome::iterate_closest(u64::MAX, 100)
+        );
+        for x in (0..1_000_000).step_by(7) {
+            let trajectory = Palindrome::iterate_closest(x, usize::MAX);
+            assert!(trajectory.len() <= 11);
+            let last = *trajectory.last().unwrap();
+            assert_eq!(last, Palindrome::closest(last.0 + 1));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_basin_of() {
+        assert_eq!((17, 28), Palindrome::basin_of(20));
+        assert_eq!((0, 1), Palindrome::basin_of(0));
+        assert_eq!((10, 17), Palindrome::basin_of(10));
+        assert_eq!((9, 10), Palindrome::basin_of(9));
+        for x in 0..5000u64 {
+            let (start, end) = Palindrome::basin_of(x);
+            assert!(start <= x && x < end, "{x}");
+            assert!(Palindrome::same_basin(x, start), "{x}");
+            assert!(Palindrome::same_basin(x, end - 1), "{x}");
+            assert!(!Palindrome::same_basin(x, end), "{x}");
+            if start > 0 {
+                assert!(!Palindrome::same_basin(x, start - 1), "{x}");
+            }
+        }
+        let (start, end) = Palindrome::basin_of(u64::MAX);
+        assert_eq!(u64::MAX, end);
+        assert!(!Palindrome::same_basin(Palindrome::MAX.0, start - 1));
+        assert!(Palindrome::same_basin(Palindrome::MAX.0, start));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_palindrome_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Ok(Palindrome::MIN), Palindrome::arbitrary(&mut u));
+        let mut u = Unstructured::new(&[0xff; 8]);
+        assert!(Palindrome::arbitrary(&mut u).is_ok_and(|p| p <= Palindrome::MAX));
+
+        let mut bytes = Vec::new();
+        let mut x = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..10_000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            bytes.push(x as u8);
+        }
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let palindrome = Palindrome::arbitrary(&mut u).unwrap();
+            assert!(Palindrome::is_palindrome(palindrome.0), "{palindrome}");
+        }
+        // A partial index from the last few bytes is still a valid palindrome.
+        for len in 0..8 {
+            let mut u = Unstructured::new(&bytes[..len]);
+            let palindrome = Palindrome::arbitrary(&mut u).unwrap();
+            assert!(Palindrome::is_palindrome(palindrome.0), "{palindrome}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn test_palindrome_proptest_arbitrary() {
+        use proptest::{
+            arbitrary::any,
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..1_000 {
+            let mut tree = any::<Palindrome>().new_tree(&mut runner).unwrap();
+            let mut previous = tree.current();
+            assert!(Palindrome::is_palindrome(previous.0), "{previous}");
+            while tree.simplify() {
+                let current = tree.current();
+                assert!(Palindrome::is_palindrome(current.0), "{current}");
+                assert!(current <= previous, "{current} > {previous}");
+                previous = current;
+            }
+            assert_eq!(Palindrome::MIN, previous);
+        }
+    }
+
+    #[test]
+    fn test_palindrome_sort_key() {
+        assert_eq!((22, 2), Palindrome::sort_key(20));
+        assert_eq!((22, 0), Palindrome::sort_key(22));
+        assert_eq!((0, 0), Palindrome::sort_key(0));
+        assert_eq!((11, 1), Palindrome::sort_key(10)); // 10 is a tie and goes to 11.
+        assert_eq!(
+            (Palindrome::MAX.0, u64::MAX - Palindrome::MAX.0),
+            Palindrome::sort_key(u64::MAX)
+        );
+
+        let mut values = vec![25, 20, 24, 121, 9, 23, 10, 119, 21];
+        values.sort_by_key(|&x| Palindrome::sort_key(x));
+        assert_eq!(vec![9, 10, 23, 21, 20, 24, 25, 121, 119], values);
+        for x in 0..5000u64 {
+            let (palindrome, distance) = Palindrome::sort_key(x);
+            assert_eq!(Palindrome::closest(x), palindrome, "{x}");
+            assert_eq!(Palindrome::closest(x).abs_diff(x), distance, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_bytes() {
+        for pal in [Palindrome::MIN, Palindrome(12321), Palindrome::MAX] {
+            assert_eq!(pal.0.to_be_bytes(), pal.to_be_bytes());
+            assert_eq!(pal.0.to_le_bytes(), pal.to_le_bytes());
+            assert_eq!(Some(pal), Palindrome::from_be_bytes(pal.to_be_bytes()));
+            assert_eq!(Some(pal), Palindrome::from_le_bytes(pal.to_le_bytes()));
+        }
+
+        // Corrupted bytes.
+        assert_eq!(None, Palindrome::from_be_bytes(12345u64.to_be_bytes()));
+        assert_eq!(None, Palindrome::from_le_bytes(12345u64.to_le_bytes()));
+        // Wrong byte order.
+        assert_eq!(None, Palindrome::from_le_bytes(12321u64.to_be_bytes()));
+    }
+
+    #[test]
+    fn test_palindrome_around_power_of_ten() {
+        assert_eq!(
+            Some((Palindrome(0), Palindrome(1))),
+            Palindrome::around_power_of_ten(0)
+        );
+        assert_eq!(
+            Some((Palindrome(9), Palindrome(11))),
+            Palindrome::around_power_of_ten(1)
+        );
+        assert_eq!(
+            Some((Palindrome(999), Palindrome(1001))),
+            Palindrome::around_power_of_ten(3)
+        );
+        assert_eq!(
+            Some((Palindrome(99999), Palindrome(100001))),
+            Palindrome::around_power_of_ten(5)
+        );
+        assert_eq!(
+            Some((
+                Palindrome(9_999_999_999_999_999_999),
+                Palindrome(10_000_000_000_000_000_001)
+            )),
+            Palindrome::around_power_of_ten(19)
+        );
+        assert_eq!(None, Palindrome::around_power_of_ten(20));
+        assert_eq!(None, Palindrome::around_power_of_ten(u32::MAX));
+        for exp in 1..20 {
+            let (below, above) = Palindrome::around_power_of_ten(exp).unwrap();
+            assert_eq!(10u64.pow(exp) - 1, below.0);
+            assert_eq!(10u64.pow(exp) + 1, above.0);
+            assert_eq!(above, below.next());
+        }
+    }
+
+    #[test]
+    fn test_palindrome_nest() {
+        assert_eq!(
+            Some(Palindrome(12321712321)),
+            Palindrome::nest(&Palindrome(12321), &Palindrome(7))
+        );
+        assert_eq!(
+            Some(Palindrome(1145411)),
+            Palindrome::nest(&Palindrome(11), &Palindrome(454))
+        );
+        assert_eq!(
+            Some(Palindrome(101)),
+            Palindrome::nest(&Palindrome(1), &Palindrome(0))
+        );
+        assert_eq!(None, Palindrome::nest(&Palindrome(0), &Palindrome(5)));
+        assert_eq!(None, Palindrome::nest(&Palindrome(0), &Palindrome(0)));
+
+        // 20 digits, but too large.
+        assert_eq!(
+            None,
+            Palindrome::nest(&Palindrome(999_999_999), &Palindrome(99))
+        );
+        // 20 digits, and small enough.
+        assert_eq!(
+            Some(Palindrome(10_000_000_111_100_000_001)),
+            Palindrome::nest(&Palindrome(100_000_001), &Palindrome(11))
+        );
+        // More than 20 digits.
+        assert_eq!(
+            None,
+            Palindrome::nest(&Palindrome(1_000_000_001), &Palindrome(1))
+        );
+        assert_eq!(None, Palindrome::nest(&Palindrome(1), &Palindrome::MAX));
+
+        for outer in PalindromeIter::from(1u64, 1_000u64) {
+            for inner in PalindromeIter::first_n(30) {
+                let nested = Palindrome::nest(&outer, &inner).unwrap();
+                assert!(Palindrome::is_palindrome(nested.0));
+                assert_eq!(format!("{outer}{inner}{outer}"), nested.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_expand_center() {
+        assert_eq!(
+            Some(Palindrome(1200021)),
+            Palindrome(1221).expand_center(0, 3)
+        );
+        assert_eq!(Some(Palindrome(10201)), Palindrome(121).expand_center(0, 2));
+        assert_eq!(
+            Some(Palindrome(1773771)),
+            Palindrome(131).expand_center(7, 4)
+        );
+        assert_eq!(Some(Palindrome(121)), Palindrome(121).expand_center(0, 0));
+        assert_eq!(Some(Palindrome(111)), Palindrome(11).expand_center(1, 1));
+        assert_eq!(Some(Palindrome(505)), Palindrome(0).expand_center(5, 2));
+        assert_eq!(Some(Palindrome(373)), Palindrome(7).expand_center(3, 2));
+        assert_eq!(None, Palindrome(121).expand_center(0, 1)); // Can't split 1 copy evenly.
+        assert_eq!(None, Palindrome(7).expand_center(0, 2)); // 070 starts with a zero.
+        assert_eq!(None, Palindrome(0).expand_center(0, 2));
+        assert_eq!(None, Palindrome(1221).expand_center(10, 2));
+        assert_eq!(None, Palindrome(1221).expand_center(0, usize::MAX));
+
+        // 20 digits, and small enough.
+        assert_eq!(
+            Some(Palindrome(10_000_000_000_000_000_001)),
+            Palindrome(11).expand_center(0, 18)
+        );
+        // 20 digits, but too large.
+        assert_eq!(None, Palindrome(99).expand_center(0, 18));
+        // More than 20 digits.
+        assert_eq!(None, Palindrome(11).expand_center(0, 19));
+        assert_eq!(None, Palindrome::MAX.expand_center(4, 2));
+
+        for palindrome in PalindromeIter::from(1u64, 100_000u64) {
+            let digits = palindrome.to_string();
+            let (left, right) = digits.split_at(digits.len() / 2);
+            for insert in 0..=9 {
+                for count in 0..=6 {
+                    let expected = if digits.len() % 2 == 0 {
+                        format!("{left}{}{right}", insert.to_string().repeat(count))
+                    } else if count % 2 == 0 {
+                        let side = insert.to_string().repeat(count / 2);
+                        format!("{left}{side}{}{side}{}", &right[..1], &right[1..])
+                    } else {
+                        assert_eq!(None, palindrome.expand_center(insert, count));
+                        continue;
+                    };
+                    if digits.len() == 1 && insert == 0 && count > 0 {
+                        assert_eq!(None, palindrome.expand_center(insert, count));
+                        continue;
+                    }
+                    let expanded = palindrome.expand_center(insert, count).unwrap();
+                    assert_eq!(
+                        expected,
+                        expanded.to_string(),
+                        "{palindrome} {insert} {count}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_to_f64() {
+        assert_eq!(121.0, Palindrome(121).to_f64());
+        assert_eq!(0.0, Palindrome::MIN.to_f64());
+        let exact = Palindrome::le(1 << 53);
+        assert_eq!(exact.0, exact.to_f64() as u64);
+        assert_eq!(Palindrome::MAX.0 as f64, Palindrome::MAX.to_f64());
+    }
+
+    #[test]
+    fn test_palindrome_to_narrower() {
+        assert_eq!(Some(4294884924), Palindrome::le(u32::MAX as u64).to_u32());
+        assert_eq!(None, Palindrome::ge(u32::MAX as u64).to_u32());
+        assert_eq!(Some(65456), Palindrome::le(u16::MAX as u64).to_u16());
+        assert_eq!(None, Palindrome::ge(u16::MAX as u64).to_u16());
+        assert_eq!(Some(252), Palindrome::le(u8::MAX as u64).to_u8());
+        assert_eq!(None, Palindrome(262).to_u8());
+        assert_eq!(Some(0), Palindrome::MIN.to_u8());
+        assert_eq!(None, Palindrome::MAX.to_u32());
+    }
+
+    #[test]
+    fn test_palindrome_to_nonzero() {
+        use std::num::NonZeroU64;
+
+        assert_eq!(None, Palindrome::MIN.to_nonzero());
+        assert_eq!(NonZeroU64::new(1), Palindrome(1).to_nonzero());
+        assert_eq!(NonZeroU64::new(12321), Palindrome(12321).to_nonzero());
+        assert_eq!(
+            NonZeroU64::new(Palindrome::MAX.0),
+            Palindrome::MAX.to_nonzero()
+        );
+
+        assert!(NonZeroU64::try_from(Palindrome::MIN).is_err());
+        assert_eq!(
+            Ok(NonZeroU64::new(12321).unwrap()),
+            NonZeroU64::try_from(Palindrome(12321))
+        );
+    }
+
+    #[test]
+    fn test_palindrome_bit_length() {
+        assert_eq!(0, Palindrome(0).bit_length());
+        assert_eq!(1, Palindrome(1).bit_length());
+        assert_eq!(3, Palindrome(5).bit_length());
+        assert_eq!(4, Palindrome(9).bit_length());
+        assert_eq!(7, Palindrome(121).bit_length());
+        assert_eq!(64, Palindrome::MAX.bit_length());
+        for bits in 1..64 {
+            let pal = Palindrome::max_for_bits(bits).unwrap();
+            assert!(pal.bit_length() <= bits, "{bits}");
+        }
+
+        assert!(Palindrome::le(u32::MAX as u64).fits_in_u32());
+        assert!(!Palindrome::ge(u32::MAX as u64).fits_in_u32());
+        assert!(Palindrome::le(u16::MAX as u64).fits_in_u16());
+        assert!(!Palindrome::ge(u16::MAX as u64).fits_in_u16());
+        assert!(Palindrome::MIN.fits_in_u16());
+        assert!(!Palindrome::MAX.fits_in_u32());
+        for pal in PalindromeIter::from(60_000u64, 70_000u64) {
+            assert_eq!(pal.to_u16().is_some(), pal.fits_in_u16(), "{pal}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_index_fraction() {
+        assert_eq!(0.0, Palindrome::MIN.index_fraction());
+        assert_eq!(1.0, Palindrome::MAX.index_fraction());
+        let half = Palindrome::nth(Palindrome::MAX_N / 2).unwrap();
+        assert!((half.index_fraction() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_palindrome_from_fraction() {
+        assert_eq!(Some(Palindrome::MIN), Palindrome::from_fraction(0.0));
+        assert_eq!(Some(Palindrome::MAX), Palindrome::from_fraction(1.0));
+        assert_eq!(Some(Palindrome::MIN), Palindrome::from_fraction(-0.5));
+        assert_eq!(Some(Palindrome::MAX), Palindrome::from_fraction(1.5));
+        assert_eq!(
+            Some(Palindrome::MIN),
+            Palindrome::from_fraction(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::from_fraction(f64::INFINITY)
+        );
+        assert_eq!(None, Palindrome::from_fraction(f64::NAN));
+        for pal in PalindromeIter::first_n(1_000).chain(PalindromeIter::first_n_from(
+            1_000,
+            Palindrome::nth(Palindrome::MAX_N - 999).unwrap(),
+        )) {
+            assert_eq!(Some(pal), Palindrome::from_fraction(pal.index_fraction()));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_from_f64() {
+        assert_eq!(Some(Palindrome(121)), Palindrome::closest_from_f64(120.6));
+        assert_eq!(Some(Palindrome(121)), Palindrome::closest_from_f64(121.0));
+        assert_eq!(Some(Palindrome(5335)), Palindrome::closest_from_f64(5340.2));
+        assert_eq!(Some(Palindrome(0)), Palindrome::closest_from_f64(0.0));
+        assert_eq!(Some(Palindrome(0)), Palindrome::closest_from_f64(-0.4)); // Rounds to `-0`.
+        assert_eq!(None, Palindrome::closest_from_f64(-0.6));
+        assert_eq!(None, Palindrome::closest_from_f64(-5.0));
+        assert_eq!(None, Palindrome::closest_from_f64(f64::NAN));
+        assert_eq!(None, Palindrome::closest_from_f64(f64::INFINITY));
+        assert_eq!(None, Palindrome::closest_from_f64(f64::NEG_INFINITY));
+        assert_eq!(None, Palindrome::closest_from_f64(u64::MAX as f64));
+        assert_eq!(None, Palindrome::closest_from_f64(1e30));
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::closest_from_f64(Palindrome::MAX.to_f64())
+        );
+        for x in 0..10_000u64 {
+            assert_eq!(
+                Some(Palindrome::closest(x)),
+                Palindrome::closest_from_f64(x as f64 + 0.25)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_coerce() {
+        assert_eq!((Palindrome(121), true), Palindrome::coerce(121));
+        assert_eq!((Palindrome(121), false), Palindrome::coerce(123));
+        assert_eq!((Palindrome(0), true), Palindrome::coerce(0));
+        assert_eq!((Palindrome(11), false), Palindrome::coerce(10));
+        assert_eq!(
+            (Palindrome::MAX, true),
+            Palindrome::coerce(Palindrome::MAX.0)
+        );
+        assert_eq!((Palindrome::MAX, false), Palindrome::coerce(u64::MAX));
+        for x in 0..10_000 {
+            assert_eq!(
+                (Palindrome::closest(x), Palindrome::is_palindrome(x)),
+                Palindrome::coerce(x)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_try_closest() {
+        use crate::DomainError;
+
+        assert_eq!(Ok(Palindrome(11)), Palindrome::try_closest(10));
+        assert_eq!(
+            Ok(Palindrome::MAX),
+            Palindrome::try_closest(Palindrome::MAX.0)
+        );
+        let err = DomainError {
+            boundary: Palindrome::MAX.0,
+        };
+        assert_eq!(Err(err), Palindrome::try_closest(Palindrome::MAX.0 + 1));
+        assert_eq!(Err(err), Palindrome::try_closest(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_new() {
+        use crate::PalindromeError;
+
+        assert_eq!(Ok(Palindrome(1221)), Palindrome::new(1221));
+        assert_eq!(Ok(Palindrome::MIN), Palindrome::new(0));
+        assert_eq!(Ok(Palindrome::MAX), Palindrome::new(Palindrome::MAX.0));
+        assert_eq!(Err(PalindromeError::NotPalindrome), Palindrome::new(1220));
+        assert_eq!(Err(PalindromeError::NotPalindrome), Palindrome::new(10));
+        assert_eq!(
+            Err(PalindromeError::NotPalindrome),
+            Palindrome::new(u64::MAX)
+        );
+        for x in 0..10_000 {
+            assert_eq!(Palindrome::new(x), Palindrome::try_from(x));
+            assert_eq!(Palindrome::is_palindrome(x), Palindrome::new(x).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_palindrome_from_u64_unchecked() {
+        const PAL: Palindrome = unsafe { Palindrome::from_u64_unchecked(12321) };
+        assert_eq!(12321, PAL);
+        assert_eq!(Palindrome::MAX, unsafe {
+            Palindrome::from_u64_unchecked(Palindrome::MAX.0)
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_palindrome_from_u64_unchecked_panic_on_non_palindrome_in_debug() {
+        let _ = unsafe { Palindrome::from_u64_unchecked(123) };
+    }
+
+    #[test]
+    fn test_palindrome_cmp_u64() {
+        use std::cmp::Ordering;
+
+        let pal = Palindrome(121);
+        assert_eq!(Ordering::Greater, pal.cmp_u64(&120));
+        assert_eq!(Ordering::Equal, pal.cmp_u64(&121));
+        assert_eq!(Ordering::Less, pal.cmp_u64(&122));
+        assert_eq!(Ordering::Less, Palindrome::MAX.cmp_u64(&u64::MAX));
+        assert_eq!(Ordering::Equal, Palindrome::MIN.cmp_u64(&0));
+    }
+
+    #[test]
+    fn test_palindrome_count() {
+        use std::ops::Bound;
+
+        assert_eq!(90, Palindrome::count(100..=999));
+        assert_eq!(90, Palindrome::count(100..1000));
+        assert_eq!(89, Palindrome::count(101..999));
+        assert_eq!(10, Palindrome::count(..10));
+        assert_eq!(11, Palindrome::count(..=11));
+        assert_eq!(0, Palindrome::count(5..5));
+        assert_eq!(1, Palindrome::count(5..=5));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 10..5;
+        assert_eq!(0, Palindrome::count(reversed));
+        assert_eq!(Palindrome::MAX_N + 1, Palindrome::count(..));
+        assert_eq!(Palindrome::MAX_N + 1, Palindrome::count(0..=u64::MAX));
+        assert_eq!(1, Palindrome::count(Palindrome::MAX.0..));
+        assert_eq!(0, Palindrome::count(Palindrome::MAX.0 + 1..));
+        assert_eq!(
+            0,
+            Palindrome::count((Bound::Excluded(u64::MAX), Bound::Unbounded))
+        );
+        assert_eq!(
+            8,
+            Palindrome::count((Bound::Excluded(1), Bound::Excluded(11)))
+        );
+        for from in (0..2_000).step_by(37) {
+            for to in (0..2_000).step_by(41) {
+                assert_eq!(
+                    (from..to).filter(|x| Palindrome::is_palindrome(*x)).count(),
+                    Palindrome::count(from..to),
+                    "{from}..{to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_count_odd_even_in_range() {
+        assert_eq!((100, 9), Palindrome::count_odd_even_in_range(0, 1_000));
+        assert_eq!((10, 0), Palindrome::count_odd_even_in_range(0, 10));
+        assert_eq!((0, 9), Palindrome::count_odd_even_in_range(10, 100));
+        assert_eq!((2, 2), Palindrome::count_odd_even_in_range(88, 112));
+        assert_eq!((0, 0), Palindrome::count_odd_even_in_range(500, 500));
+        assert_eq!((0, 0), Palindrome::count_odd_even_in_range(500, 100));
+        let (odd, even) = Palindrome::count_odd_even_in_range(0, u64::MAX);
+        assert_eq!(Palindrome::MAX_N + 1, odd + even);
+        assert!(odd > even);
+        for from in (0..20_000).step_by(997) {
+            for to in (0..20_000).step_by(1_009) {
+                let (mut odd, mut even) = (0, 0);
+                for x in from..to {
+                    if Palindrome::is_palindrome(x) {
+                        if x.to_string().len() % 2 == 1 {
+                            odd += 1;
+                        } else {
+                            even += 1;
+                        }
+                    }
+                }
+                assert_eq!(
+                    (odd, even),
+                    Palindrome::count_odd_even_in_range(from, to),
+                    "{from}..{to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_count_starting_with() {
+        for max in [
+            0u64, 1, 9, 10, 11, 100, 101, 555, 1000, 12321, 12345, 98789, 100_000,
+        ] {
+            for digit in 0..=9 {
+                let expected = PalindromeIter::from(0u64, max + 1)
+                    .filter(|p| p.to_string().starts_with((b'0' + digit) as char))
+                    .count();
+                assert_eq!(
+                    expected,
+                    Palindrome::count_starting_with(digit, max),
+                    "{digit} {max}"
+                );
+            }
+        }
+
+        let total: usize = (0..=9)
+            .map(|digit| Palindrome::count_starting_with(digit, u64::MAX))
+            .sum();
+        assert_eq!(Palindrome::MAX_N + 1, total);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindrome_count_starting_with_panic_on_invalid_digit() {
+        Palindrome::count_starting_with(10, 1000);
+    }
+
+    #[test]
+    fn test_palindrome_iter_around() {
+        let around: Vec<Palindrome> = Palindrome::iter_around(150).take(5).collect();
+        assert_eq!(vec![151, 141, 161, 131, 171], around);
+
+        // Deduplicated when `x` is a palindrome.
+        let around: Vec<Palindrome> = Palindrome::iter_around(121).take(3).collect();
+        assert_eq!(vec![121, 131, 111], around);
+
+        // Ties yield the higher palindrome first.
+        let around: Vec<Palindrome> = Palindrome::iter_around(10).take(3).collect();
+        assert_eq!(vec![11, 9, 8], around);
+
+        // Continues in one direction once the other is exhausted.
+        let around: Vec<Palindrome> = Palindrome::iter_around(1).take(4).collect();
+        assert_eq!(vec![1, 2, 0, 3], around);
+        let around: Vec<Palindrome> = Palindrome::iter_around(u64::MAX).take(2).collect();
+        assert_eq!(vec![Palindrome::MAX, Palindrome::MAX.previous()], around);
+        let around: Vec<Palindrome> = Palindrome::iter_around(Palindrome::MAX.0).take(2).collect();
+        assert_eq!(vec![Palindrome::MAX, Palindrome::MAX.previous()], around);
+
+        let around: Vec<Palindrome> = Palindrome::iter_around(0).take(20).collect();
+        assert_eq!(PalindromeIter::first_n(20).collect::<Vec<_>>(), around);
+    }
+
+    #[test]
+    fn test_palindrome_closest_even() {
+        assert_eq!(Palindrome(898), Palindrome::closest_even(1001));
+        assert_eq!(Palindrome(0), Palindrome::closest_even(0));
+        assert_eq!(Palindrome(2), Palindrome::closest_even(1)); // Tie between 0 and 2.
+        assert_eq!(Palindrome(22), Palindrome::closest_even(15));
+        assert_eq!(Palindrome(2002), Palindrome::closest_even(1999));
+        assert_eq!(
+            Palindrome(8_999_999_999_999_999_998),
+            Palindrome::closest_even(u64::MAX)
+        );
+        assert_eq!(
+            Palindrome(8_999_999_999_999_999_998),
+            Palindrome::closest_even(Palindrome::MAX.0)
+        );
+        for x in 0..30_000 {
+            assert_eq!(
+                Palindrome::closest_multiple_of(x, 2).unwrap(),
+                Palindrome::closest_even(x),
+                "{x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_odd() {
+        assert_eq!(Palindrome(1991), Palindrome::closest_odd(2002));
+        assert_eq!(Palindrome(1), Palindrome::closest_odd(0));
+        assert_eq!(Palindrome(1001), Palindrome::closest_odd(1001));
+        assert_eq!(Palindrome(11), Palindrome::closest_odd(10)); // Tie between 9 and 11.
+        assert_eq!(Palindrome::MAX, Palindrome::closest_odd(u64::MAX));
+        assert_eq!(
+            Palindrome(9_000_000_000_000_000_009),
+            Palindrome::closest_odd(8_999_999_999_999_999_999)
+        );
+        for x in 0..30_000 {
+            let expected = Palindrome::iter_around(x).find(|p| p.0 % 2 == 1).unwrap();
+            assert_eq!(expected, Palindrome::closest_odd(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_closest_multiple_of() {
+        assert_eq!(None, Palindrome::closest_multiple_of(100, 0));
+        assert_eq!(
+            Palindrome(99),
+            Palindrome::closest_multiple_of(100, 11).unwrap()
+        );
+        assert_eq!(
+            Palindrome(1001),
+            Palindrome::closest_multiple_of(1000, 11).unwrap()
+        );
+        assert_eq!(
+            Palindrome(121),
+            Palindrome::closest_multiple_of(121, 1).unwrap()
+        );
+        assert_eq!(
+            Palindrome(8),
+            Palindrome::closest_multiple_of(10, 2).unwrap()
+        );
+        assert_eq!(
+            Palindrome(252),
+            Palindrome::closest_multiple_of(250, 2).unwrap()
+        );
+        assert_eq!(
+            Palindrome(0),
+            Palindrome::closest_multiple_of(7, 1_000).unwrap()
+        );
+        assert_eq!(
+            Palindrome(0),
+            Palindrome::closest_multiple_of(0, 7).unwrap()
+        );
+        assert_eq!(
+            Palindrome::MIN,
+            Palindrome::closest_multiple_of(12345, u64::MAX).unwrap()
+        );
+        // Only `0` is a multiple of `10`, however large `x` is.
+        for k in [10u64, 20, 50, 1_000] {
+            for x in [1_000_000_000u64, 10u64.pow(13), Palindrome::MAX.0, u64::MAX] {
+                assert_eq!(
+                    Some(Palindrome::MIN),
+                    Palindrome::closest_multiple_of(x, k),
+                    "{x} {k}"
+                );
+            }
+        }
+        assert_eq!(
+            Palindrome::MIN,
+            Palindrome::closest_multiple_of(9, 10).unwrap()
+        );
+
+        for (x, k) in [(500u64, 7u64), (12345, 13), (99_999, 17), (3, 4), (4, 8)] {
+            let expected = Palindrome::iter_around(x).find(|p| p.0 % k == 0);
+            assert_eq!(expected, Palindrome::closest_multiple_of(x, k), "{x} {k}");
+            let expected = expected.unwrap();
+            let brute = PalindromeIter::from(0u64, 2 * x + k)
+                .filter(|p| p.0 % k == 0)
+                .min_by_key(|p| p.abs_diff(x))
+                .unwrap();
+            assert_eq!(brute.abs_diff(x), expected.abs_diff(x), "{x} {k}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_is_repdigit() {
+        assert!(Palindrome(0).is_repdigit());
+        assert!(Palindrome(7).is_repdigit());
+        assert!(Palindrome(77).is_repdigit());
+        assert!(Palindrome(777).is_repdigit());
+        assert!(!Palindrome(717).is_repdigit());
+        assert!(!Palindrome(101).is_repdigit());
+        assert!(!Palindrome::MAX.is_repdigit());
+        assert!(Palindrome(11_111_111_111_111_111_111).is_repdigit());
+    }
+
+    #[test]
+    fn test_palindrome_nth_repdigit() {
+        assert_eq!(Some(Palindrome(0)), Palindrome::nth_repdigit(0));
+        assert_eq!(Some(Palindrome(9)), Palindrome::nth_repdigit(9));
+        assert_eq!(Some(Palindrome(11)), Palindrome::nth_repdigit(10));
+        assert_eq!(Some(Palindrome(99)), Palindrome::nth_repdigit(18));
+        assert_eq!(Some(Palindrome(111)), Palindrome::nth_repdigit(19));
+        assert_eq!(
+            Some(Palindrome(11_111_111_111_111_111_111)),
+            Palindrome::nth_repdigit(172)
+        );
+        assert_eq!(None, Palindrome::nth_repdigit(173));
+        assert_eq!(None, Palindrome::nth_repdigit(usize::MAX));
+
+        let repdigits: Vec<Palindrome> = PalindromeIter::from(0u64, 1_000_000u64)
+            .filter(|p| p.is_repdigit())
+            .collect();
+        for (n, repdigit) in repdigits.into_iter().enumerate() {
+            assert_eq!(Some(repdigit), Palindrome::nth_repdigit(n));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_to_digit_array() {
+        assert_eq!(Ok([0, 0, 1, 2, 1]), Palindrome(121).to_digit_array::<5>());
+        assert_eq!(Ok([1, 2, 1]), Palindrome(121).to_digit_array::<3>());
+        assert_eq!(Err(3), Palindrome(121).to_digit_array::<2>());
+        assert_eq!(Ok([0]), Palindrome(0).to_digit_array::<1>());
+        assert_eq!(Err(1), Palindrome(0).to_digit_array::<0>());
+        assert_eq!(Ok([0, 0, 7]), Palindrome(7).to_digit_array::<3>());
+        assert_eq!(
+            Ok([1, 8, 4, 4, 6, 7, 4, 4, 0, 6, 6, 0, 4, 4, 7, 6, 4, 4, 8, 1]),
+            Palindrome::MAX.to_digit_array::<20>()
+        );
+        assert_eq!(Err(20), Palindrome::MAX.to_digit_array::<19>());
+    }
+
+    #[test]
+    fn test_palindrome_digit_at() {
+        let pal = Palindrome(12321);
+        assert_eq!(Some(1), pal.digit_at(0));
+        assert_eq!(Some(2), pal.digit_at(1));
+        assert_eq!(Some(3), pal.digit_at(2));
+        assert_eq!(None, pal.digit_at(5));
+        assert_eq!(None, pal.digit_at(u32::MAX));
+        assert_eq!(Some(0), Palindrome::MIN.digit_at(0));
+        assert_eq!(None, Palindrome::MIN.digit_at(1));
+        assert_eq!(Some(1), Palindrome::MAX.digit_at(0));
+        assert_eq!(Some(6), Palindrome::MAX.digit_at(9));
+        assert_eq!(Some(1), Palindrome::MAX.digit_at(19));
+        assert_eq!(None, Palindrome::MAX.digit_at(20));
+        for pal in PalindromeIter::first_n(5_000).chain([Palindrome::MAX]) {
+            let digits = pal.to_string();
+            let len = digits.len() as u32;
+            for (idx, digit) in digits.bytes().enumerate() {
+                let idx = idx as u32;
+                assert_eq!(Some(digit - b'0'), pal.digit_at(idx));
+                assert_eq!(pal.digit_at(idx), pal.digit_at(len - 1 - idx));
+            }
+            assert_eq!(None, pal.digit_at(len));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_ilog10() {
+        assert_eq!(None, Palindrome::MIN.ilog10());
+        assert_eq!(Some(0), Palindrome(1).ilog10());
+        assert_eq!(Some(0), Palindrome(9).ilog10());
+        assert_eq!(Some(1), Palindrome(11).ilog10());
+        assert_eq!(Some(4), Palindrome(12321).ilog10());
+        assert_eq!(Some(19), Palindrome::MAX.ilog10());
     }
-}
 
-impl Iterator for PalindromeIter {
-    type Item = Palindrome;
+    #[test]
+    fn test_palindrome_is_fibonacci() {
+        assert!(Palindrome(0).is_fibonacci());
+        assert!(Palindrome(1).is_fibonacci());
+        assert!(Palindrome(8).is_fibonacci());
+        assert!(Palindrome(55).is_fibonacci());
+        assert!(!Palindrome(4).is_fibonacci());
+        assert!(!Palindrome(66).is_fibonacci());
+        assert!(!Palindrome::MAX.is_fibonacci());
+        let mut fibonacci = vec![0u64, 1];
+        while let Some(next) =
+            fibonacci[fibonacci.len() - 2].checked_add(fibonacci[fibonacci.len() - 1])
+        {
+            fibonacci.push(next);
+        }
+        for pal in PalindromeIter::first_n(10_000) {
+            assert_eq!(fibonacci.contains(&pal.0), pal.is_fibonacci(), "{pal}");
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.from < self.to {
-            let next_palindrome = self.from;
-            self.from = self.from.next();
-            return Some(next_palindrome);
-        } else {
-            return None;
+    #[test]
+    fn test_palindrome_isqrt() {
+        for x in (0..10_000u128).chain([
+            u64::MAX as u128,
+            u64::MAX as u128 * u64::MAX as u128,
+            u128::MAX,
+        ]) {
+            let root = Palindrome::isqrt(x);
+            assert!(root * root <= x, "{x}");
+            if let Some(square) = (root + 1).checked_mul(root + 1) {
+                assert!(square > x, "{x}");
+            }
         }
+        assert_eq!(u64::MAX as u128, Palindrome::isqrt(u128::MAX));
     }
-}
 
-pub trait IsPalindrome {
-    /// Return whether `self` is a palindrome.
-    fn is_palindrome(&self) -> bool;
-}
+    #[test]
+    fn test_palindrome_fibonacci_palindromes() {
+        let palindromes = Palindrome::fibonacci_palindromes();
+        assert_eq!(
+            vec![0, 1, 2, 3, 5, 8, 55],
+            palindromes.iter().map(|p| p.0).collect::<Vec<_>>()
+        );
+        assert!(palindromes.iter().all(Palindrome::is_fibonacci));
+    }
 
-impl IsPalindrome for u64 {
-    fn is_palindrome(&self) -> bool {
-        if *self % 10 == 0 && *self != 0 {
-            return false;
-        }
+    #[test]
+    fn test_palindrome_is_palindrome_doubled() {
+        assert!(Palindrome::MIN.is_palindrome_doubled());
+        assert!(Palindrome(4).is_palindrome_doubled());
+        assert!(!Palindrome(5).is_palindrome_doubled());
+        assert!(Palindrome(121).is_palindrome_doubled());
+        assert!(!Palindrome(151).is_palindrome_doubled());
+        assert!(!Palindrome::MAX.is_palindrome_doubled());
+        assert!(Palindrome(4_444_444_444_444_444_444).is_palindrome_doubled());
+        assert!(!Palindrome(9_999_999_999_999_999_999).is_palindrome_doubled());
+        // Overflows.
+    }
 
-        let mut x = *self;
-        let mut right_half = 0;
-        while x > right_half {
-            right_half = right_half * 10 + x % 10;
-            x /= 10;
+    #[test]
+    fn test_palindrome_digit_shift() {
+        assert_eq!(Some(Palindrome(202)), Palindrome(191).digit_shift(1));
+        assert_eq!(Some(Palindrome(191)), Palindrome(191).digit_shift(0));
+        assert_eq!(Some(Palindrome(191)), Palindrome(191).digit_shift(10));
+        assert_eq!(Some(Palindrome(0)), Palindrome(9).digit_shift(1));
+        assert_eq!(Some(Palindrome(3)), Palindrome(0).digit_shift(3));
+        assert_eq!(Some(Palindrome(3)), Palindrome(0).digit_shift(253));
+        assert_eq!(None, Palindrome(989).digit_shift(1));
+        assert_eq!(None, Palindrome::MAX.digit_shift(1));
+        assert_eq!(
+            Some(Palindrome(9_888_888_888_888_888_889)),
+            Palindrome(1_000_000_000_000_000_001).digit_shift(8)
+        );
+        assert_eq!(
+            None,
+            Palindrome(10_000_000_000_000_000_001).digit_shift(1) // Larger than MAX.
+        );
+        for pal in PalindromeIter::first_n(2_000) {
+            for k in 0..10 {
+                if let Some(shifted) = pal.digit_shift(k) {
+                    assert!(Palindrome::is_palindrome(shifted.0));
+                    assert_eq!(Some(pal), shifted.digit_shift(10 - k));
+                }
+            }
         }
-
-        return x == right_half || x == right_half / 10;
     }
-}
 
-impl IsPalindrome for u32 {
-    fn is_palindrome(&self) -> bool {
-        (*self as u64).is_palindrome()
+    #[test]
+    fn test_palindrome_digit_sum() {
+        assert_eq!(9, Palindrome(12321).digit_sum());
+        assert_eq!(12, Palindrome(123321).digit_sum());
+        assert_eq!(0, Palindrome(0).digit_sum());
+        assert_eq!(7, Palindrome(7).digit_sum());
+        assert_eq!(171, Palindrome::largest_with_digits(19).digit_sum());
+        assert_eq!(88, Palindrome::MAX.digit_sum());
+        for pal in PalindromeIter::first_n(5000) {
+            let expected: u32 = pal.to_string().bytes().map(|b| (b - b'0') as u32).sum();
+            assert_eq!(expected, pal.digit_sum(), "{pal}");
+        }
     }
-}
 
-impl IsPalindrome for u16 {
-    fn is_palindrome(&self) -> bool {
-        (*self as u64).is_palindrome()
+    #[test]
+    fn test_palindrome_with_digit_sum() {
+        assert_eq!(
+            vec![4, 22, 121, 202, 1111, 2002],
+            Palindrome::with_digit_sum(4, 10_000)
+                .map(|pal| pal.0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Palindrome(0)],
+            Palindrome::with_digit_sum(0, u64::MAX).collect::<Vec<_>>()
+        );
+        assert_eq!(0, Palindrome::with_digit_sum(4, 4).count());
+        assert_eq!(0, Palindrome::with_digit_sum(1_000, u64::MAX).count());
+        let twos: Vec<Palindrome> = Palindrome::with_digit_sum(2, u64::MAX).collect();
+        assert_eq!(20, twos.len()); // 2, 11, 101, 1001 and so on.
+        assert_eq!(Some(&Palindrome(10u64.pow(19) + 1)), twos.last());
+        assert_eq!(0, Palindrome::with_digit_sum(180, u64::MAX).count()); // Only 99..9 > MAX.
+        assert_eq!(
+            Some(Palindrome::largest_with_digits(19)),
+            Palindrome::with_digit_sum(171, u64::MAX).last()
+        );
+
+        let all: Vec<Palindrome> = PalindromeIter::from(0u64, 1_000_000u64).collect();
+        for sum in 0..=60 {
+            let expected: Vec<Palindrome> = all
+                .iter()
+                .copied()
+                .filter(|pal| pal.digit_sum() == sum)
+                .collect();
+            let generated: Vec<Palindrome> = Palindrome::with_digit_sum(sum, 1_000_000).collect();
+            assert_eq!(expected, generated, "{sum}");
+        }
     }
-}
 
-impl IsPalindrome for u8 {
-    fn is_palindrome(&self) -> bool {
-        (*self as u64).is_palindrome()
+    #[test]
+    fn test_palindrome_half_digit_sum() {
+        assert_eq!(6, Palindrome(12321).half_digit_sum());
+        assert_eq!(6, Palindrome(123321).half_digit_sum());
+        assert_eq!(0, Palindrome(0).half_digit_sum());
+        assert_eq!(7, Palindrome(7).half_digit_sum());
+        assert_eq!(1, Palindrome(11).half_digit_sum());
+        assert_eq!(44, Palindrome::MAX.half_digit_sum());
+        for pal in PalindromeIter::first_n(5000) {
+            let digits = pal.to_string().into_bytes();
+            let expected: u32 = digits[..digits.len().div_ceil(2)]
+                .iter()
+                .map(|b| (b - b'0') as u32)
+                .sum();
+            assert_eq!(expected, pal.half_digit_sum(), "{pal}");
+        }
     }
-}
 
-impl IsPalindrome for Palindrome {
-    #[inline]
-    fn is_palindrome(&self) -> bool {
-        true
+    #[test]
+    fn test_palindrome_digital_root() {
+        assert_eq!(0, Palindrome(0).digital_root());
+        assert_eq!(9, Palindrome(9).digital_root());
+        assert_eq!(9, Palindrome(12321).digital_root());
+        assert_eq!(5, Palindrome(98789).digital_root());
+        for pal in PalindromeIter::first_n(5000) {
+            let mut root = pal.digit_sum();
+            while root >= 10 {
+                root = root.to_string().bytes().map(|b| (b - b'0') as u32).sum();
+            }
+            assert_eq!(root as u8, pal.digital_root(), "{pal}");
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::PalindromeIter;
+    #[test]
+    fn test_palindrome_geometric_closest() {
+        let closest = |a, b| Palindrome::geometric_closest(Palindrome(a), Palindrome(b));
+        assert_eq!(4, closest(2, 8));
+        assert_eq!(22, closest(11, 44)); // sqrt(484) = 22.
+        assert_eq!(33, closest(11, 99)); // sqrt(1089) = 33.
+        assert_eq!(66, closest(9, 474)); // sqrt(4266) = 65.3...
+        assert_eq!(121, closest(121, 121));
+        assert_eq!(0, closest(0, 12321));
+        assert_eq!(0, closest(12321, 0));
+        assert_eq!(
+            Palindrome::MAX,
+            closest(Palindrome::MAX.0, Palindrome::MAX.0)
+        );
+        assert_eq!(
+            Palindrome::closest(4_294_967_295),
+            closest(1, Palindrome::MAX.0)
+        );
+    }
 
-    use super::Palindrome;
+    #[test]
+    fn test_palindrome_rotations() {
+        assert_eq!(
+            vec![Palindrome(1221), Palindrome(2112)],
+            Palindrome(1221).rotations()
+        );
+        assert_eq!(vec![Palindrome(1111)], Palindrome(1111).rotations());
+        assert_eq!(vec![Palindrome(1001)], Palindrome(1001).rotations());
+        assert_eq!(vec![Palindrome(121)], Palindrome(121).rotations());
+        assert_eq!(
+            vec![Palindrome(123_321), Palindrome(321_123)],
+            Palindrome(123_321).rotations()
+        );
+        assert_eq!(vec![Palindrome(7)], Palindrome(7).rotations());
+        assert_eq!(vec![Palindrome::MIN], Palindrome::MIN.rotations());
+        // Moving the first half to the back would be `60_447_644_811_844_674_406`.
+        assert_eq!(vec![Palindrome::MAX], Palindrome::MAX.rotations());
+        // `21_122_112_211_221_122_112` is a palindrome, but larger than MAX.
+        assert_eq!(
+            vec![Palindrome(12_211_221_122_112_211_221)],
+            Palindrome(12_211_221_122_112_211_221).rotations()
+        );
+        assert_eq!(
+            vec![Palindrome(12_211_221), Palindrome(21_122_112)],
+            Palindrome(12_211_221).rotations()
+        );
+        for pal in PalindromeIter::first_n(5_000) {
+            let digits = pal.to_string();
+            let mut expected = Vec::new();
+            for moved in 0..digits.len() {
+                let rotated = format!("{}{}", &digits[moved..], &digits[..moved]);
+                let value: u64 = rotated.parse().unwrap();
+                if (!rotated.starts_with('0') || rotated == "0")
+                    && Palindrome::is_palindrome(value)
+                    && !expected.contains(&Palindrome(value))
+                {
+                    expected.push(Palindrome(value));
+                }
+            }
+            assert_eq!(expected, pal.rotations(), "{pal}");
+        }
+    }
 
     #[test]
-    fn test_palindrome_closest() {
-        assert_eq!(11, Palindrome::closest(10));
-        assert_eq!(38783, Palindrome::closest(38794));
-        assert_eq!(38783, Palindrome::closest(38832));
-        assert_eq!(38883, Palindrome::closest(38833));
-        assert_eq!(943858349, Palindrome::closest(943854534));
+    fn test_palindrome_reflect() {
+        assert_eq!(Some(Palindrome(32123)), Palindrome(12321).reflect());
+        assert_eq!(Some(Palindrome(321123)), Palindrome(123321).reflect());
+        assert_eq!(Some(Palindrome(12321)), Palindrome(32123).reflect());
+        assert_eq!(Some(Palindrome(212)), Palindrome(121).reflect());
+        assert_eq!(Some(Palindrome(111)), Palindrome(111).reflect());
+        assert_eq!(Some(Palindrome(7)), Palindrome(7).reflect());
+        assert_eq!(Some(Palindrome(0)), Palindrome(0).reflect());
+        assert_eq!(None, Palindrome(12021).reflect());
+        assert_eq!(None, Palindrome(1001).reflect());
+        assert_eq!(Some(Palindrome(2112)), Palindrome(1221).reflect());
+        // 60447644811844674406 is larger than u64::MAX.
+        assert_eq!(None, Palindrome::MAX.reflect());
     }
 
     #[test]
     fn test_palindrome_construct() {
         let pd = Palindrome::to_digits(345);
-        assert_eq!(34543, Palindrome::construct(&pd, 5));
-        assert_eq!(345543, Palindrome::construct(&pd, 6));
+        assert_eq!(34543, Palindrome::construct(&pd, 5).unwrap());
+        assert_eq!(345543, Palindrome::construct(&pd, 6).unwrap());
         let pd = Palindrome::to_digits(0);
-        assert_eq!(0, Palindrome::construct(&pd, 1));
-        assert_eq!(0, Palindrome::construct(&pd, 2));
+        assert_eq!(0, Palindrome::construct(&pd, 1).unwrap());
+        assert_eq!(0, Palindrome::construct(&pd, 2).unwrap());
         let pd = Palindrome::to_digits(1710);
-        assert_eq!(1710171, Palindrome::construct(&pd, 7));
-        assert_eq!(17100171, Palindrome::construct(&pd, 8));
+        assert_eq!(1710171, Palindrome::construct(&pd, 7).unwrap());
+        assert_eq!(17100171, Palindrome::construct(&pd, 8).unwrap());
+    }
+
+    #[test]
+    fn test_palindrome_construct_20_digits() {
+        let pd = Palindrome::to_digits(1844674406);
+        assert_eq!(Some(Palindrome::MAX), Palindrome::construct(&pd, 20));
+        // 18446744077044764481 is larger than u64::MAX.
+        let pd = Palindrome::to_digits(1844674407);
+        assert_eq!(None, Palindrome::construct(&pd, 20));
+        assert_eq!(Palindrome::MAX, Palindrome::construct_saturating(&pd, 20));
+        let pd = Palindrome::to_digits(9999999999);
+        assert_eq!(None, Palindrome::construct(&pd, 20));
+        assert_eq!(
+            Some(Palindrome(9_999_999_999_999_999_999)),
+            Palindrome::construct(&pd, 19)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindrome_construct_panic_on_too_short_length() {
+        let pd = Palindrome::to_digits(345);
+        assert_eq!(34543, Palindrome::construct(&pd, 4).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindrome_construct_panic_on_too_big_length() {
+        let pd = Palindrome::to_digits(345);
+        assert_eq!(34543, Palindrome::construct(&pd, 7).unwrap());
+    }
+
+    #[test]
+    fn test_palindrome_single_digits() {
+        for (n, pal) in Palindrome::SINGLE_DIGITS.iter().enumerate() {
+            assert_eq!(Palindrome::nth(n).unwrap(), *pal);
+        }
+    }
+
+    #[test]
+    fn test_palindrome_max_for_bits() {
+        assert_eq!(Some(Palindrome::MIN), Palindrome::max_for_bits(0));
+        assert_eq!(Some(Palindrome(1)), Palindrome::max_for_bits(1));
+        assert_eq!(Some(Palindrome(7)), Palindrome::max_for_bits(3));
+        assert_eq!(Some(Palindrome(252)), Palindrome::max_for_bits(8));
+        assert_eq!(Some(Palindrome(65456)), Palindrome::max_for_bits(16));
+        assert_eq!(Some(Palindrome(4294884924)), Palindrome::max_for_bits(32));
+        assert_eq!(Some(Palindrome::MAX), Palindrome::max_for_bits(64));
+        assert_eq!(None, Palindrome::max_for_bits(65));
+        assert_eq!(None, Palindrome::max_for_bits(u32::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_closest_with_length() {
+        assert_eq!(Palindrome(99), Palindrome::closest_with_length(500, 2));
+        assert_eq!(Palindrome(101), Palindrome::closest_with_length(5, 3));
+        assert_eq!(Palindrome(11), Palindrome::closest_with_length(0, 2));
+        assert_eq!(Palindrome(0), Palindrome::closest_with_length(0, 1));
+        assert_eq!(Palindrome(9), Palindrome::closest_with_length(1_000, 1));
+        assert_eq!(Palindrome(5335), Palindrome::closest_with_length(5340, 4));
+        assert_eq!(Palindrome(1111), Palindrome::closest_with_length(1056, 4)); // Tie.
+        assert_eq!(Palindrome(9), Palindrome::closest_with_length(9, 0));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::closest_with_length(u64::MAX, 20)
+        );
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::closest_with_length(u64::MAX, 99)
+        );
+        assert_eq!(
+            Palindrome(10_000_000_000_000_000_001),
+            Palindrome::closest_with_length(0, 20)
+        );
+        let palindromes: Vec<Palindrome> = PalindromeIter::from(0u64, 2_000_000u64).collect();
+        for n in 1..=6 {
+            for x in (0..2_000_000).step_by(4_999) {
+                let expected = *palindromes
+                    .iter()
+                    .filter(|p| p.0.to_string().len() as u32 == n)
+                    .min_by_key(|p| (p.0.abs_diff(x), u64::MAX - p.0))
+                    .unwrap();
+                assert_eq!(expected, Palindrome::closest_with_length(x, n), "{x} {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_largest_with_digits() {
+        const LARGEST_3_DIGIT: Palindrome = Palindrome::largest_with_digits(3);
+        assert_eq!(999, LARGEST_3_DIGIT);
+        assert_eq!(Palindrome::MIN, Palindrome::largest_with_digits(0));
+        assert_eq!(9, Palindrome::largest_with_digits(1));
+        assert_eq!(99, Palindrome::largest_with_digits(2));
+        assert_eq!(
+            9_999_999_999_999_999_999,
+            Palindrome::largest_with_digits(19)
+        );
+        assert_eq!(Palindrome::MAX, Palindrome::largest_with_digits(20));
+        assert_eq!(Palindrome::MAX, Palindrome::largest_with_digits(u32::MAX));
+        for n in 1..20 {
+            let largest = Palindrome::largest_with_digits(n);
+            assert_eq!(10u64.pow(n) + 1, largest.next().0);
+        }
+    }
+
+    #[test]
+    fn test_palindrome_try_from_digits() {
+        use crate::PalindromeError;
+
+        assert_eq!(Ok(Palindrome(121)), Palindrome::try_from_digits(&[1, 2, 1]));
+        assert_eq!(
+            Ok(Palindrome(1221)),
+            Palindrome::try_from_digits(&[1, 2, 2, 1])
+        );
+        assert_eq!(Ok(Palindrome(0)), Palindrome::try_from_digits(&[0]));
+        assert_eq!(Ok(Palindrome(7)), Palindrome::try_from_digits(&[7]));
+        assert_eq!(
+            Ok(Palindrome::MAX),
+            Palindrome::try_from_digits(&[
+                1, 8, 4, 4, 6, 7, 4, 4, 0, 6, 6, 0, 4, 4, 7, 6, 4, 4, 8, 1
+            ])
+        );
+        assert_eq!(
+            Err(PalindromeError::Empty),
+            Palindrome::try_from_digits(&[])
+        );
+        assert_eq!(
+            Err(PalindromeError::InvalidDigit(10)),
+            Palindrome::try_from_digits(&[10, 1, 10])
+        );
+        assert_eq!(
+            Err(PalindromeError::LeadingZero),
+            Palindrome::try_from_digits(&[0, 1, 0])
+        );
+        assert_eq!(
+            Err(PalindromeError::NotPalindrome),
+            Palindrome::try_from_digits(&[1, 2, 3])
+        );
+        assert_eq!(
+            Err(PalindromeError::Overflow),
+            Palindrome::try_from_digits(&[9; 20])
+        );
+        assert_eq!(
+            Err(PalindromeError::Overflow),
+            Palindrome::try_from_digits(&[1; 21])
+        );
+    }
+
+    #[test]
+    fn test_palindrome_render_with() {
+        fn roman(mut x: u64) -> String {
+            const NUMERALS: [(u64, &str); 13] = [
+                (1000, "M"),
+                (900, "CM"),
+                (500, "D"),
+                (400, "CD"),
+                (100, "C"),
+                (90, "XC"),
+                (50, "L"),
+                (40, "XL"),
+                (10, "X"),
+                (9, "IX"),
+                (5, "V"),
+                (4, "IV"),
+                (1, "I"),
+            ];
+            let mut numeral = String::new();
+            for (value, symbol) in NUMERALS {
+                while x >= value {
+                    numeral.push_str(symbol);
+                    x -= value;
+                }
+            }
+            numeral
+        }
+
+        assert_eq!("V", Palindrome(5).render_with(roman));
+        assert_eq!("CXXI", Palindrome(121).render_with(roman));
+        assert_eq!("MMII", Palindrome(2002).render_with(roman));
+        assert_eq!("0x79", Palindrome(121).render_with(|x| format!("{x:#x}")));
+        assert_eq!(
+            Palindrome::MAX.to_string(),
+            Palindrome::MAX.render_with(|x| x.to_string())
+        );
+    }
+
+    #[test]
+    fn test_palindrome_format_mirrored() {
+        assert_eq!("|0|", Palindrome(0).format_mirrored('|'));
+        assert_eq!("|5|", Palindrome(5).format_mirrored('|'));
+        assert_eq!("1|1", Palindrome(11).format_mirrored('|'));
+        assert_eq!("1|2|1", Palindrome(121).format_mirrored('|'));
+        assert_eq!("123|321", Palindrome(123321).format_mirrored('|'));
+        assert_eq!("12|3|21", Palindrome::closest(12321).format_mirrored('|'));
+        assert_eq!("12·3·21", Palindrome(12321).format_mirrored('·'));
+        assert_eq!(
+            "1844674406 6044764481",
+            Palindrome::MAX.format_mirrored(' ')
+        );
+    }
+
+    #[test]
+    fn test_palindrome_to_grouped_string() {
+        assert_eq!("0", Palindrome(0).to_grouped_string(','));
+        assert_eq!("121", Palindrome(121).to_grouped_string(','));
+        assert_eq!("1,221", Palindrome(1221).to_grouped_string(','));
+        assert_eq!("12,321", Palindrome(12321).to_grouped_string(','));
+        assert_eq!("123,321", Palindrome(123321).to_grouped_string(','));
+        assert_eq!("1 234 321", Palindrome(1234321).to_grouped_string(' '));
+        assert_eq!(
+            "18_446_744_066_044_764_481",
+            Palindrome::MAX.to_grouped_string('_')
+        );
+        assert_eq!("1’234’321", Palindrome(1234321).to_grouped_string('’'));
+        assert_eq!("1234321", Palindrome(1234321).to_string());
+    }
+
+    #[test]
+    fn test_palindrome_nth() {
+        // REMEMBER IT'S 0-BASED INDEXING.
+        for n in 0..=9 {
+            assert_eq!(n as u64, Palindrome::nth(n).unwrap());
+        }
+
+        // Test large nth values.
+        let n = 438907;
+        let mut pal = Palindrome::le(0);
+        for _ in 0..n {
+            pal = pal.next();
+        }
+        assert_eq!(pal, Palindrome::nth(n).unwrap());
+
+        // Another one.
+        let n = 9999;
+        let mut pal = Palindrome::le(0);
+        for _ in 0..n {
+            pal = pal.next();
+        }
+        assert_eq!(pal, Palindrome::nth(n).unwrap());
+
+        // And another one.
+        let n = 109834;
+        let mut pal = Palindrome::le(0);
+        for _ in 0..n {
+            pal = pal.next();
+        }
+        assert_eq!(pal, Palindrome::nth(n).unwrap());
+
+        // Test None values.
+        let n = Palindrome::MAX_N; // 0-based indexing.
+        assert_eq!(Palindrome::MAX, Palindrome::nth(n).unwrap());
+        let n = Palindrome::MAX_N + 1; // 0-based indexing.
+        assert_eq!(None, Palindrome::nth(n));
+    }
+
+    #[test]
+    fn test_palindrome_to_n() {
+        assert_eq!(0, Palindrome(0).to_n());
+        assert_eq!(1, Palindrome(1).to_n());
+        assert_eq!(9, Palindrome(9).to_n());
+        assert_eq!(10, Palindrome(11).to_n());
+        assert_eq!(19, Palindrome(101).to_n());
+        assert_eq!(Palindrome::MAX_N, Palindrome::MAX.to_n());
+
+        // Exact inverse of `nth`, one palindrome after another.
+        for (n, pal) in PalindromeIter::first_n(100_000).enumerate() {
+            assert_eq!(n, pal.to_n(), "{pal}");
+            assert_eq!(Some(pal), Palindrome::nth(pal.to_n()), "{pal}");
+        }
+
+        // Around every change in length.
+        for exp in 1..=19 {
+            let power = 10u64.pow(exp);
+            let (largest, smallest) = (Palindrome::le(power), Palindrome::ge(power));
+            assert_eq!(largest.to_n() + 1, smallest.to_n(), "{smallest}");
+            for pal in [largest.previous(), largest, smallest, smallest.next()] {
+                assert_eq!(Some(pal), Palindrome::nth(pal.to_n()), "{pal}");
+            }
+        }
+        for n in [0, 9, 10, Palindrome::MAX_N - 1, Palindrome::MAX_N] {
+            assert_eq!(n, Palindrome::nth(n).unwrap().to_n());
+        }
+    }
+
+    #[test]
+    fn test_palindrome_nth_saturating() {
+        assert_eq!(Palindrome::MIN, Palindrome::nth_saturating(0));
+        assert_eq!(11, Palindrome::nth_saturating(10));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::nth_saturating(Palindrome::MAX_N)
+        );
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::nth_saturating(Palindrome::MAX_N + 1)
+        );
+        assert_eq!(Palindrome::MAX, Palindrome::nth_saturating(usize::MAX));
+        for n in (0..1_000_000).step_by(997) {
+            assert_eq!(Palindrome::nth(n), Some(Palindrome::nth_saturating(n)));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_partition_point() {
+        // First palindrome whose square exceeds 10^12.
+        let idx = Palindrome::partition_point(|p| (p.0 as u128).pow(2) <= 1_000_000_000_000);
+        assert_eq!(1_000_001, Palindrome::nth(idx).unwrap());
+        assert_eq!(999_999, Palindrome::nth(idx - 1).unwrap());
+
+        assert_eq!(0, Palindrome::partition_point(|_| false));
+        assert_eq!(Palindrome::MAX_N + 1, Palindrome::partition_point(|_| true));
+        assert_eq!(
+            Palindrome::MAX_N,
+            Palindrome::partition_point(|p| p < Palindrome::MAX)
+        );
+        for x in [0u64, 1, 10, 11, 12, 999, 1000, 12345] {
+            let idx = Palindrome::partition_point(|p| p < x);
+            assert_eq!(Palindrome::ge(x), Palindrome::nth(idx).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_palindrome_count_primes_below() {
+        assert_eq!(0, Palindrome::count_primes_below(0));
+        assert_eq!(0, Palindrome::count_primes_below(2));
+        assert_eq!(1, Palindrome::count_primes_below(3));
+        assert_eq!(4, Palindrome::count_primes_below(11));
+        assert_eq!(5, Palindrome::count_primes_below(12));
+        assert_eq!(10, Palindrome::count_primes_below(200));
+        assert_eq!(20, Palindrome::count_primes_below(1_000));
+        assert_eq!(113, Palindrome::count_primes_below(100_000));
+        assert_eq!(781, Palindrome::count_primes_below(10_000_000));
+        assert_eq!(5953, Palindrome::count_primes_below(1_000_000_000));
+
+        let brute_force = |x: u64| {
+            (0..x)
+                .filter(|&y| Palindrome::is_palindrome(y) && y >= 2 && (2..y).all(|d| y % d != 0))
+                .count()
+        };
+        for x in [
+            0, 1, 5, 100, 101, 102, 131, 192, 1000, 10_000, 10_301, 10_302, 30_000,
+        ] {
+            assert_eq!(brute_force(x), Palindrome::count_primes_below(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_primes_in_range() {
+        let primes = |from, to| Palindrome::primes_in_range(from, to).collect::<Vec<_>>();
+        assert_eq!(vec![11, 101, 131, 151, 181, 191], primes(10, 200));
+        assert_eq!(vec![2, 3, 5, 7, 11], primes(0, 100));
+        assert_eq!(vec![11], primes(11, 12));
+        assert!(primes(12, 101).is_empty());
+        assert!(primes(1000, 10_000).is_empty()); // Every palindrome is a multiple of 11.
+        assert!(primes(200, 10).is_empty());
+        assert!(primes(7, 7).is_empty());
+        assert_eq!(vec![10_301, 10_501, 10_601], primes(10_000, 10_700));
+        assert_eq!(vec![1_003_001], primes(1_000_000, 1_004_000));
+        assert_eq!(vec![3_001_003], primes(2_000_000, 3_001_004));
+
+        let brute_force = |from: u64, to: u64| {
+            (from..to)
+                .filter(|&y| Palindrome::is_palindrome(y) && y >= 2 && (2..y).all(|d| y % d != 0))
+                .collect::<Vec<_>>()
+        };
+        for (from, to) in [
+            (0, 30_000),
+            (5, 12),
+            (100, 200),
+            (150, 20_000),
+            (30_000, 40_000),
+        ] {
+            assert_eq!(brute_force(from, to), primes(from, to), "{from}..{to}");
+        }
+
+        // The top of the range is 19 digits, since every 20-digit palindrome is skipped.
+        let largest = Palindrome::primes_in_range(9_999_990_000_000_000_000, u64::MAX).last();
+        assert!(largest.is_some_and(|p| p.0 < 10_000_000_000_000_000_000));
+        assert_eq!(
+            Palindrome::count_primes_below(1_000_000_000),
+            Palindrome::primes_in_range(0, 1_000_000_000).count()
+        );
+    }
+
+    #[test]
+    fn test_palindrome_closest_prime() {
+        assert_eq!(Palindrome(191), Palindrome::closest_prime(200));
+        assert_eq!(Palindrome(2), Palindrome::closest_prime(0));
+        assert_eq!(Palindrome(2), Palindrome::closest_prime(1));
+        assert_eq!(Palindrome(11), Palindrome::closest_prime(11));
+        assert_eq!(Palindrome(11), Palindrome::closest_prime(50));
+        assert_eq!(Palindrome(101), Palindrome::closest_prime(60));
+        assert_eq!(Palindrome(11), Palindrome::closest_prime(55));
+        assert_eq!(Palindrome(101), Palindrome::closest_prime(56)); // Tie between 11 and 101.
+        assert_eq!(Palindrome(10301), Palindrome::closest_prime(9_999));
+
+        // No palindrome with 20 digits is prime, so everything above `10^19` ends at the same one.
+        let largest = Palindrome::closest_prime(10u64.pow(19) - 1);
+        assert!(Palindrome::is_prime(largest.0));
+        assert_eq!(19, largest.0.to_string().len());
+        assert_eq!(largest, Palindrome::closest_prime(10u64.pow(19)));
+        assert_eq!(largest, Palindrome::closest_prime(Palindrome::MAX.0));
+        assert_eq!(largest, Palindrome::closest_prime(u64::MAX));
+
+        for x in 0..20_000 {
+            let expected = Palindrome::iter_around(x)
+                .find(|p| Palindrome::is_prime(p.0))
+                .unwrap();
+            assert_eq!(expected, Palindrome::closest_prime(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_is_prime() {
+        let primes: Vec<u64> = (0..100).filter(|&x| Palindrome::is_prime(x)).collect();
+        assert_eq!(
+            vec![
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97
+            ],
+            primes
+        );
+        assert!(Palindrome::is_prime(18446744073709551557)); // Largest u64 prime.
+        assert!(!Palindrome::is_prime(u64::MAX));
+        assert!(!Palindrome::is_prime(3215031751)); // Strong pseudoprime to bases 2, 3, 5, 7.
+        assert!(!Palindrome::is_prime(1_000_000_007 * 998_244_353));
+        assert!(Palindrome::is_prime(1_000_000_007));
+    }
+
+    #[test]
+    fn test_palindrome_next_via_half() {
+        assert_eq!(Palindrome(1), Palindrome(0).next_via_half());
+        assert_eq!(Palindrome(11), Palindrome(9).next_via_half());
+        assert_eq!(Palindrome(22), Palindrome(11).next_via_half());
+        assert_eq!(Palindrome(101), Palindrome(99).next_via_half());
+        assert_eq!(Palindrome(1001), Palindrome(999).next_via_half());
+        assert_eq!(Palindrome(12421), Palindrome(12321).next_via_half());
+        assert_eq!(Palindrome(13031), Palindrome(12921).next_via_half());
+        assert_eq!(Palindrome(20002), Palindrome(19991).next_via_half());
+        assert_eq!(
+            Palindrome(10u64.pow(19) + 1),
+            Palindrome::largest_with_digits(19).next_via_half()
+        );
+        assert_eq!(Palindrome::MAX, Palindrome::MAX.previous().next_via_half());
+        assert_eq!(Palindrome::MAX, Palindrome::MAX.next_via_half());
+        for pal in PalindromeIter::first_n(100_000) {
+            assert_eq!(Palindrome::ge(pal.0 + 1), pal.next_via_half(), "{pal}");
+        }
+        for length in 2..=19 {
+            let pal = Palindrome::largest_with_digits(length);
+            assert_eq!(Palindrome::ge(pal.0 + 1), pal.next_via_half(), "{pal}");
+            let pal = pal.previous();
+            assert_eq!(Palindrome::ge(pal.0 + 1), pal.next_via_half(), "{pal}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_next_length() {
+        assert_eq!(Some(Palindrome(11)), Palindrome::MIN.next_length());
+        assert_eq!(Some(Palindrome(11)), Palindrome(9).next_length());
+        assert_eq!(Some(Palindrome(101)), Palindrome(11).next_length());
+        assert_eq!(Some(Palindrome(1001)), Palindrome(121).next_length());
+        assert_eq!(Some(Palindrome(1001)), Palindrome(999).next_length());
+        assert_eq!(
+            Some(Palindrome(10u64.pow(19) + 1)),
+            Palindrome::largest_with_digits(19).next_length()
+        );
+        assert_eq!(None, Palindrome(10u64.pow(19) + 1).next_length());
+        assert_eq!(None, Palindrome::MAX.next_length());
+    }
+
+    #[test]
+    fn test_palindrome_previous_length() {
+        assert_eq!(None, Palindrome::MIN.previous_length());
+        assert_eq!(None, Palindrome(9).previous_length());
+        assert_eq!(Some(Palindrome(9)), Palindrome(11).previous_length());
+        assert_eq!(Some(Palindrome(99)), Palindrome(101).previous_length());
+        assert_eq!(Some(Palindrome(99)), Palindrome(999).previous_length());
+        assert_eq!(
+            Some(Palindrome::largest_with_digits(19)),
+            Palindrome::MAX.previous_length()
+        );
+        for pal in PalindromeIter::from(10u64, 100_000u64) {
+            let previous = pal.previous_length().unwrap();
+            assert_eq!(previous.next_length().unwrap().previous(), previous);
+        }
+    }
+
+    #[test]
+    fn test_palindrome_shrink() {
+        let shrunk: Vec<Palindrome> = Palindrome(1001).shrink().collect();
+        assert_eq!(
+            vec![
+                Palindrome(454),
+                Palindrome(181),
+                Palindrome(44),
+                Palindrome(6),
+                Palindrome(3),
+                Palindrome(1),
+                Palindrome(0)
+            ],
+            shrunk
+        );
+        assert_eq!(0, Palindrome::MIN.shrink().count());
+        assert_eq!(
+            vec![Palindrome(0)],
+            Palindrome(1).shrink().collect::<Vec<_>>()
+        );
+
+        let shrunk: Vec<Palindrome> = Palindrome::MAX.shrink().collect();
+        assert_eq!(Some(&Palindrome::MIN), shrunk.last());
+        assert!(shrunk.windows(2).all(|w| w[0] > w[1]));
+        assert!(shrunk[0] < Palindrome::MAX);
+        assert_eq!(Palindrome::nth(Palindrome::MAX_N / 2), Some(shrunk[0]));
+    }
+
+    #[test]
+    fn test_palindrome_palindrome_factors() {
+        assert_eq!(
+            vec![
+                (Palindrome(1), 121),
+                (Palindrome(11), 11),
+                (Palindrome(121), 1)
+            ],
+            Palindrome(121).palindrome_factors()
+        );
+        assert_eq!(
+            vec![
+                (Palindrome(1), 252),
+                (Palindrome(2), 126),
+                (Palindrome(3), 84),
+                (Palindrome(4), 63),
+                (Palindrome(6), 42),
+                (Palindrome(7), 36),
+                (Palindrome(9), 28),
+                (Palindrome(252), 1)
+            ],
+            Palindrome(252).palindrome_factors()
+        );
+        assert_eq!(vec![(Palindrome(1), 1)], Palindrome(1).palindrome_factors());
+        assert!(Palindrome::MIN.palindrome_factors().is_empty());
+        for pal in PalindromeIter::first_n(300) {
+            let expected: Vec<_> = (1..=pal.0)
+                .filter(|d| pal.0 % d == 0 && Palindrome::is_palindrome(*d))
+                .map(|d| (Palindrome(d), pal.0 / d))
+                .collect();
+            assert_eq!(expected, pal.palindrome_factors(), "{pal}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_is_smith() {
+        let smiths: Vec<u64> = PalindromeIter::from(0u64, 1_000u64)
+            .filter(|pal| pal.is_smith())
+            .map(|pal| pal.0)
+            .collect();
+        assert_eq!(vec![4, 22, 121, 202, 454, 535, 636, 666], smiths);
+        assert!(!Palindrome(0).is_smith());
+        assert!(!Palindrome(1).is_smith());
+        assert!(!Palindrome(727).is_smith()); // Prime.
+        assert!(!Palindrome(11).is_smith()); // Prime.
+
+        let digit_sum = |x: u64| {
+            x.to_string()
+                .bytes()
+                .map(|b| (b - b'0') as u32)
+                .sum::<u32>()
+        };
+        for pal in PalindromeIter::from(0u64, 100_000u64) {
+            let mut factor_sum = 0;
+            let mut rest = pal.0;
+            let mut factors = 0;
+            let mut d = 2;
+            while rest > 1 {
+                while rest % d == 0 {
+                    factor_sum += digit_sum(d);
+                    rest /= d;
+                    factors += 1;
+                }
+                d += 1;
+            }
+            let expected = factors > 1 && factor_sum == digit_sum(pal.0);
+            assert_eq!(expected, pal.is_smith(), "{pal}");
+        }
+        // Large composites, where the last prime factor is found without trial division.
+        assert!(!Palindrome(10_000_000_000_000_000_001).is_smith()); // 11 * 909090909090909091
+        assert!(!Palindrome(11_111_111_111_111_111_111).is_smith());
+    }
+
+    #[test]
+    fn test_palindrome_splits_into_palindromes() {
+        assert_eq!(
+            vec![(1, 111), (11, 11), (111, 1)],
+            Palindrome(1111).splits_into_palindromes()
+        );
+        assert_eq!(vec![(1, 1)], Palindrome(11).splits_into_palindromes());
+        assert_eq!(
+            vec![(101, 101)],
+            Palindrome(101101).splits_into_palindromes()
+        );
+        assert!(Palindrome(1001).splits_into_palindromes().is_empty());
+        assert!(Palindrome(1221).splits_into_palindromes().is_empty());
+        assert!(Palindrome(5).splits_into_palindromes().is_empty());
+        assert!(Palindrome::MIN.splits_into_palindromes().is_empty());
+        for pal in PalindromeIter::first_n(5_000) {
+            let digits = pal.to_string();
+            let expected: Vec<(u64, u64)> = (1..digits.len())
+                .map(|idx| digits.split_at(idx))
+                .filter(|(left, right)| {
+                    left.chars().eq(left.chars().rev()) && right.chars().eq(right.chars().rev())
+                })
+                .map(|(left, right)| (left.parse().unwrap(), right.parse().unwrap()))
+                .collect();
+            assert_eq!(expected, pal.splits_into_palindromes(), "{pal}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_as_palindrome_sum() {
+        assert_eq!(
+            Some((Palindrome(22), Palindrome(99))),
+            Palindrome(121).as_palindrome_sum()
+        );
+        assert_eq!(
+            Some((Palindrome(1), Palindrome(1))),
+            Palindrome(2).as_palindrome_sum()
+        );
+        assert_eq!(
+            Some((Palindrome(2), Palindrome(9))),
+            Palindrome(11).as_palindrome_sum()
+        );
+        assert_eq!(None, Palindrome(0).as_palindrome_sum());
+        assert_eq!(None, Palindrome(1).as_palindrome_sum());
+        for pal in PalindromeIter::first_n(300) {
+            match pal.as_palindrome_sum() {
+                Some((a, b)) => {
+                    assert!(a <= b && b < pal && a.0 > 0);
+                    assert_eq!(pal.0, a + b);
+                }
+                None => assert!(
+                    (1..pal.0)
+                        .all(|a| !Palindrome::is_palindrome(a)
+                            || !Palindrome::is_palindrome(pal.0 - a)),
+                    "{pal}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_add_snap() {
+        assert_eq!(
+            Some(Palindrome(222)),
+            Palindrome(121).add_snap(&Palindrome(99))
+        );
+        assert_eq!(
+            Some(Palindrome(252)),
+            Palindrome(121).add_snap(&Palindrome(131))
+        );
+        assert_eq!(Some(Palindrome(0)), Palindrome(0).add_snap(&Palindrome(0)));
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::MAX.add_snap(&Palindrome::MIN)
+        );
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::MAX.add_snap(&Palindrome::nth(9).unwrap())
+        );
+        assert_eq!(None, Palindrome::MAX.add_snap(&Palindrome::MAX));
+        // Adding values isn't the same as adding palindrome-indices.
+        assert_ne!(
+            Palindrome(121).add_snap(&Palindrome(99)),
+            Palindrome(121).checked_next_n(Palindrome(99).to_n())
+        );
+        for a in PalindromeIter::first_n(200) {
+            for b in PalindromeIter::first_n(200) {
+                assert_eq!(Some(Palindrome::closest(a.0 + b.0)), a.add_snap(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_div_exact_palindrome() {
+        assert_eq!(
+            Some(Palindrome(121)),
+            Palindrome(484).div_exact_palindrome(&Palindrome(4))
+        );
+        assert_eq!(
+            Some(Palindrome(22)),
+            Palindrome(484).div_exact_palindrome(&Palindrome(22))
+        );
+        assert_eq!(None, Palindrome(484).div_exact_palindrome(&Palindrome(3)));
+        assert_eq!(None, Palindrome(252).div_exact_palindrome(&Palindrome(2))); // 126
+        assert_eq!(None, Palindrome(484).div_exact_palindrome(&Palindrome(0)));
+        assert_eq!(None, Palindrome(0).div_exact_palindrome(&Palindrome(0)));
+        assert_eq!(
+            Some(Palindrome(0)),
+            Palindrome(0).div_exact_palindrome(&Palindrome(7))
+        );
+        assert_eq!(
+            Some(Palindrome(1)),
+            Palindrome::MAX.div_exact_palindrome(&Palindrome::MAX)
+        );
+        for a in PalindromeIter::first_n(300) {
+            for b in PalindromeIter::from(1u64, 1_000u64) {
+                let expected =
+                    (a % b == 0 && Palindrome::is_palindrome(a / b)).then(|| Palindrome(a / b));
+                assert_eq!(expected, a.div_exact_palindrome(&b), "{a} / {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_mul_snap() {
+        assert_eq!(Some(Palindrome(363)), Palindrome(121).mul_snap(3));
+        assert_eq!(Some(Palindrome(484)), Palindrome(121).mul_snap(4));
+        assert_eq!(Some(Palindrome(1221)), Palindrome(121).mul_snap(10)); // 1210
+        assert_eq!(Some(Palindrome(0)), Palindrome(121).mul_snap(0));
+        assert_eq!(Some(Palindrome(0)), Palindrome(0).mul_snap(u64::MAX));
+        assert_eq!(Some(Palindrome::MAX), Palindrome::MAX.mul_snap(1));
+        assert_eq!(None, Palindrome::MAX.mul_snap(2));
+        assert_eq!(None, Palindrome(2).mul_snap(u64::MAX));
+        assert_eq!(Some(Palindrome::MAX), Palindrome(1).mul_snap(u64::MAX));
+        for x in 0..1_000 {
+            let palindrome = Palindrome::closest(x);
+            for rhs in 0..20 {
+                assert_eq!(
+                    Some(Palindrome::closest(palindrome.0 * rhs)),
+                    palindrome.mul_snap(rhs)
+                );
+            }
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_palindrome_construct_panic_on_too_short_length() {
-        let pd = Palindrome::to_digits(345);
-        assert_eq!(34543, Palindrome::construct(&pd, 4));
+    fn test_palindrome_all_descending() {
+        let mut descending = Palindrome::all_descending();
+        assert_eq!(Some(Palindrome::MAX), descending.next());
+        assert_eq!(Some(Palindrome::MAX.previous()), descending.next());
+        let top: Vec<_> = Palindrome::all_descending().take(100).collect();
+        assert_eq!(100, top.len());
+        assert!(top.windows(2).all(|pair| pair[1] == pair[0].previous()));
+
+        // Skipping to the end with `nth` doesn't iterate one by one.
+        let mut descending = Palindrome::all_descending();
+        assert_eq!(Some(Palindrome(1)), descending.nth(Palindrome::MAX_N - 1));
+        assert_eq!(Some(Palindrome::MIN), descending.next());
+        assert_eq!(None, descending.next());
+        assert_eq!(None, descending.next());
     }
 
     #[test]
-    #[should_panic]
-    fn test_palindrome_construct_panic_on_too_big_length() {
-        let pd = Palindrome::to_digits(345);
-        assert_eq!(34543, Palindrome::construct(&pd, 7));
+    fn test_palindrome_by_length() {
+        let bands: Vec<_> = Palindrome::by_length(3).collect();
+        assert_eq!(3, bands.len());
+        assert_eq!(
+            vec![10, 9, 90],
+            bands.iter().map(PalindromeIter::len).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            PalindromeIter::from(0u64, 1_000u64).collect::<Vec<_>>(),
+            bands.into_iter().flatten().collect::<Vec<_>>()
+        );
+        assert_eq!(0, Palindrome::by_length(0).count());
+        assert_eq!(20, Palindrome::by_length(u32::MAX).count());
+        let total: usize = Palindrome::by_length(20).map(|band| band.len()).sum();
+        assert_eq!(Palindrome::MAX_N + 1, total);
+        for (length, mut band) in (1..).zip(Palindrome::by_length(20)) {
+            // Not every 20-digit palindrome fits in a `u64`.
+            let highest =
+                PalindromeIter::palindromes_in_n_digits(length).min(Palindrome::MAX_N + 1);
+            assert_eq!(
+                highest - PalindromeIter::palindromes_in_n_digits(length - 1),
+                band.len()
+            );
+            assert_eq!(Palindrome::nth_with_length(0, length as u32), band.next());
+            assert_eq!(length as usize, band.last().unwrap().to_string().len());
+        }
     }
 
     #[test]
-    fn test_palindrome_nth() {
-        // REMEMBER IT'S 0-BASED INDEXING.
-        for n in 0..=9 {
-            assert_eq!(n as u64, Palindrome::nth(n).unwrap());
-        }
-
-        // Test large nth values.
-        let n = 438907;
-        let mut pal = Palindrome::le(0);
-        for _ in 0..n {
-            pal = pal.next();
+    fn test_palindrome_seed_range() {
+        assert_eq!((10, 100), Palindrome::seed_range(4));
+        assert_eq!((100, 1000), Palindrome::seed_range(5));
+        assert_eq!((0, 10), Palindrome::seed_range(1));
+        assert_eq!((1, 10), Palindrome::seed_range(2));
+        assert_eq!((10, 100), Palindrome::seed_range(3));
+        assert_eq!((0, 0), Palindrome::seed_range(0));
+        assert_eq!((0, 0), Palindrome::seed_range(21));
+        assert_eq!((1_000_000_000, 10_000_000_000), Palindrome::seed_range(19));
+        assert_eq!((1_000_000_000, 1_844_674_407), Palindrome::seed_range(20));
+
+        for length in 1..=Palindrome::MAX_LEN as u32 {
+            let (low, high) = Palindrome::seed_range(length);
+            let first = Palindrome::construct(&Palindrome::to_digits(low), length as usize);
+            let last = Palindrome::construct(&Palindrome::to_digits(high - 1), length as usize);
+            assert_eq!(Palindrome::nth_with_length(0, length), first, "{length}");
+            assert_eq!(
+                Some(Palindrome::largest_with_digits(length)),
+                last,
+                "{length}"
+            );
         }
-        assert_eq!(pal, Palindrome::nth(n).unwrap());
-
-        // Another one.
-        let n = 9999;
-        let mut pal = Palindrome::le(0);
-        for _ in 0..n {
-            pal = pal.next();
+        for length in 1..=7 {
+            let (low, high) = Palindrome::seed_range(length);
+            let palindromes = PalindromeIter::from(0u64, 10_000_000u64)
+                .filter(|pal| pal.to_string().len() as u32 == length)
+                .count();
+            assert_eq!(palindromes as u64, high - low, "{length}");
         }
-        assert_eq!(pal, Palindrome::nth(n).unwrap());
+    }
 
-        // And another one.
-        let n = 109834;
-        let mut pal = Palindrome::le(0);
-        for _ in 0..n {
-            pal = pal.next();
+    #[test]
+    fn test_palindrome_nth_with_length() {
+        assert_eq!(Some(Palindrome(1221)), Palindrome::nth_with_length(2, 4));
+        assert_eq!(Some(Palindrome(1001)), Palindrome::nth_with_length(0, 4));
+        assert_eq!(Some(Palindrome(9999)), Palindrome::nth_with_length(89, 4));
+        assert_eq!(None, Palindrome::nth_with_length(90, 4));
+        assert_eq!(Some(Palindrome(0)), Palindrome::nth_with_length(0, 1));
+        assert_eq!(Some(Palindrome(9)), Palindrome::nth_with_length(9, 1));
+        assert_eq!(None, Palindrome::nth_with_length(10, 1));
+        assert_eq!(Some(Palindrome(11)), Palindrome::nth_with_length(0, 2));
+        assert_eq!(Some(Palindrome(10001)), Palindrome::nth_with_length(0, 5));
+        assert_eq!(None, Palindrome::nth_with_length(0, 0));
+        assert_eq!(None, Palindrome::nth_with_length(0, 21));
+        let max_offset = Palindrome::MAX_N - Palindrome::ge(10u64.pow(19)).to_n();
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::nth_with_length(max_offset, 20)
+        );
+        assert_eq!(None, Palindrome::nth_with_length(max_offset + 1, 20));
+        for length in 1..=7 {
+            let first = Palindrome::nth_with_length(0, length).unwrap();
+            for (n, pal) in PalindromeIter::first_n_from(50, first).enumerate() {
+                if pal.to_string().len() as u32 != length {
+                    break;
+                }
+                assert_eq!(Some(pal), Palindrome::nth_with_length(n, length));
+            }
         }
-        assert_eq!(pal, Palindrome::nth(n).unwrap());
-
-        // Test None values.
-        let n = Palindrome::MAX_N; // 0-based indexing.
-        assert_eq!(Palindrome::MAX, Palindrome::nth(n).unwrap());
-        let n = Palindrome::MAX_N + 1; // 0-based indexing.
-        assert_eq!(None, Palindrome::nth(n));
     }
 
     #[test]
@@ -1042,6 +7849,69 @@ mod tests {
         assert_eq!(999, pal.previous())
     }
 
+    #[test]
+    fn test_palindrome_previous_length_decrease() {
+        assert_eq!(9, Palindrome(11).previous());
+        assert_eq!(99, Palindrome(101).previous());
+        assert_eq!(999, Palindrome(1001).previous());
+        assert_eq!(9999, Palindrome(10001).previous());
+        assert_eq!(0, Palindrome(1).previous());
+
+        // Every length change, from both sides.
+        for exp in 1..=19 {
+            let smallest = Palindrome(10u64.pow(exp) + 1);
+            let largest = Palindrome(10u64.pow(exp) - 1);
+            assert_eq!(largest, smallest.previous(), "{smallest}");
+            assert_eq!(largest, Palindrome::le(10u64.pow(exp)), "{smallest}");
+            assert_eq!(largest, Palindrome::le(smallest.0 - 1), "{smallest}");
+        }
+
+        // Exhaustively, against the palindromes in ascending order.
+        let all: Vec<Palindrome> = PalindromeIter::from(0u64, 1_000_000u64).collect();
+        for pair in all.windows(2) {
+            assert_eq!(pair[0], pair[1].previous(), "{}", pair[1]);
+        }
+        let mut expected = Palindrome::MIN;
+        for x in 0..200_000u64 {
+            if Palindrome::is_palindrome(x) {
+                expected = Palindrome(x);
+            }
+            assert_eq!(expected, Palindrome::le(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_next_at_least() {
+        assert_eq!(Palindrome(222), Palindrome(121).next_at_least(100));
+        assert_eq!(Palindrome(222), Palindrome(121).next_at_least(101));
+        assert_eq!(Palindrome(232), Palindrome(121).next_at_least(102));
+        assert_eq!(Palindrome(121), Palindrome(121).next_at_least(0));
+        assert_eq!(Palindrome(131), Palindrome(121).next_at_least(1));
+        assert_eq!(Palindrome(11), Palindrome(9).next_at_least(1));
+        assert_eq!(Palindrome::MAX, Palindrome(121).next_at_least(u64::MAX));
+        assert_eq!(Palindrome::MAX, Palindrome::MAX.next_at_least(1));
+
+        // Thin out the small palindromes to a gap of at least 50.
+        let mut palindrome = Palindrome::MIN;
+        let mut thinned = vec![palindrome];
+        while palindrome.0 < 300 {
+            palindrome = palindrome.next_at_least(50);
+            thinned.push(palindrome);
+        }
+        assert_eq!(
+            vec![
+                Palindrome(0),
+                Palindrome(55),
+                Palindrome(111),
+                Palindrome(161),
+                Palindrome(212),
+                Palindrome(262),
+                Palindrome(313)
+            ],
+            thinned
+        );
+    }
+
     #[test]
     fn test_palindrome_next() {
         let pal = Palindrome(22);
@@ -1060,6 +7930,339 @@ mod tests {
         assert_eq!(1991, pal.next());
     }
 
+    #[test]
+    fn test_palindrome_checked_next() {
+        assert_eq!(Some(Palindrome(1)), Palindrome::MIN.checked_next());
+        assert_eq!(Some(Palindrome(1001)), Palindrome(999).checked_next());
+        assert_eq!(Some(Palindrome(131)), Palindrome(121).checked_next());
+
+        // The very top of the range.
+        let below_max = Palindrome::MAX.previous();
+        assert_eq!(18_446_744_055_044_764_481, below_max);
+        assert_eq!(Some(Palindrome::MAX), below_max.checked_next());
+        assert_eq!(None, Palindrome::MAX.checked_next());
+        assert_eq!(Palindrome::MAX, Palindrome::le(u64::MAX));
+        assert_eq!(Some(below_max), below_max.previous().checked_next());
+    }
+
+    #[test]
+    fn test_palindrome_scaled() {
+        let pal = Palindrome(12321);
+        assert_eq!(18481, pal.scaled(3, 2).unwrap());
+        assert_eq!(12321, pal.scaled(1, 1).unwrap());
+        assert_eq!(6116, pal.scaled(1, 2).unwrap());
+        assert_eq!(0, pal.scaled(0, 7).unwrap());
+        assert_eq!(None, pal.scaled(3, 0));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::MAX.scaled(u64::MAX, 1).unwrap()
+        );
+        assert_eq!(
+            Palindrome::closest(Palindrome::MAX.0 / 2),
+            Palindrome::MAX.scaled(u64::MAX / 2, u64::MAX).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_palindrome_reverse() {
+        assert_eq!(0, Palindrome::reverse(0));
+        assert_eq!(321, Palindrome::reverse(123));
+        assert_eq!(321, Palindrome::reverse(1230));
+        assert_eq!(51_615_590_737_044_764_481, Palindrome::reverse(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_is_reverse_of() {
+        assert!(Palindrome(121).is_reverse_of(121));
+        assert!(Palindrome(1221).is_reverse_of(12210));
+        assert!(Palindrome(1221).is_reverse_of(1_221_000));
+        assert!(!Palindrome(1221).is_reverse_of(1222));
+        assert!(!Palindrome(121).is_reverse_of(12_101));
+        assert!(Palindrome(0).is_reverse_of(0));
+        assert!(!Palindrome(0).is_reverse_of(10));
+        assert!(Palindrome(1).is_reverse_of(10_000_000_000_000_000_000));
+        assert!(Palindrome::MAX.is_reverse_of(Palindrome::MAX.0));
+        assert!(!Palindrome::MAX.is_reverse_of(u64::MAX));
+        for x in 0..20_000u64 {
+            let pal = Palindrome::closest(x);
+            let trimmed = (0..5)
+                .map(|zeros| 10u64.pow(zeros))
+                .any(|power| x % power == 0 && x / power == pal.0);
+            assert_eq!(trimmed, pal.is_reverse_of(x), "{pal} {x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_classify_reverse_add() {
+        use crate::ReverseAddResult;
+
+        assert_eq!(
+            ReverseAddResult::Palindrome(Palindrome(121), 1),
+            Palindrome::classify_reverse_add(56, 10)
+        );
+        assert_eq!(
+            ReverseAddResult::Palindrome(Palindrome(1111), 3),
+            Palindrome::classify_reverse_add(59, 10) // 59 -> 154 -> 605 -> 1111
+        );
+        assert_eq!(
+            ReverseAddResult::Palindrome(Palindrome(242), 1),
+            Palindrome::classify_reverse_add(121, 10)
+        );
+        assert_eq!(
+            ReverseAddResult::Palindrome(Palindrome(0), 1),
+            Palindrome::classify_reverse_add(0, 1)
+        );
+        assert_eq!(
+            ReverseAddResult::Palindrome(Palindrome(8_813_200_023_188), 24),
+            Palindrome::classify_reverse_add(89, 100)
+        );
+        assert_eq!(
+            ReverseAddResult::Suspected,
+            Palindrome::classify_reverse_add(89, 23)
+        );
+        assert_eq!(
+            ReverseAddResult::Suspected,
+            Palindrome::classify_reverse_add(56, 0)
+        );
+        assert!(matches!(
+            Palindrome::classify_reverse_add(196, 1_000),
+            ReverseAddResult::Overflowed(_)
+        ));
+        assert_eq!(
+            ReverseAddResult::Suspected,
+            Palindrome::classify_reverse_add(196, 5)
+        );
+        assert_eq!(
+            ReverseAddResult::Overflowed(u64::MAX),
+            Palindrome::classify_reverse_add(u64::MAX, 1)
+        );
+        for start in 0..1_000 {
+            if let ReverseAddResult::Palindrome(palindrome, 1) =
+                Palindrome::classify_reverse_add(start, 1)
+            {
+                assert!(palindrome
+                    .reverse_add_predecessors(start + 1)
+                    .contains(&start));
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_reverse_add_predecessors() {
+        let pal = Palindrome(121);
+        assert_eq!(
+            vec![29, 38, 47, 56, 65, 74, 83, 92, 110],
+            pal.reverse_add_predecessors(1000)
+        );
+        assert_eq!(vec![29, 38, 47], pal.reverse_add_predecessors(50));
+        assert_eq!(Vec::<u64>::new(), pal.reverse_add_predecessors(0));
+        assert_eq!(vec![0], Palindrome(0).reverse_add_predecessors(10));
+        assert_eq!(vec![1], Palindrome(2).reverse_add_predecessors(10));
+        assert_eq!(
+            Vec::<u64>::new(),
+            Palindrome(1).reverse_add_predecessors(10)
+        );
+        assert_eq!(vec![10], Palindrome(11).reverse_add_predecessors(100));
+
+        for pal in PalindromeIter::first_n(300) {
+            let expected: Vec<u64> = (0..=pal.0)
+                .filter(|&x| x as u128 + Palindrome::reverse(x) == pal.0 as u128)
+                .collect();
+            assert_eq!(expected, pal.reverse_add_predecessors(u64::MAX), "{pal}");
+        }
+
+        // Large palindromes return right away, instead of scanning every number below them.
+        assert_eq!(
+            vec![1_000_000_000],
+            Palindrome(1_000_000_001).reverse_add_predecessors(u64::MAX)
+        );
+        let predecessors = Palindrome::MAX.reverse_add_predecessors(u64::MAX);
+        assert_eq!(2_205_000, predecessors.len());
+        assert!(predecessors.windows(2).all(|w| w[0] < w[1]));
+        for &x in predecessors.iter().step_by(997) {
+            assert_eq!(
+                Palindrome::MAX.0 as u128,
+                x as u128 + Palindrome::reverse(x),
+                "{x}"
+            );
+        }
+        let x = 1_000_000_000_200_000_001u64;
+        let pal = Palindrome::new((x as u128 + Palindrome::reverse(x)) as u64).unwrap();
+        assert!(pal.reverse_add_predecessors(u64::MAX).contains(&x));
+        assert!(!pal.reverse_add_predecessors(x).contains(&x));
+    }
+
+    #[test]
+    fn test_palindrome_count_between() {
+        assert_eq!(
+            3,
+            Palindrome::count_between(Palindrome(99), Palindrome(121))
+        );
+        assert_eq!(
+            0,
+            Palindrome::count_between(Palindrome(121), Palindrome(99))
+        );
+        assert_eq!(
+            0,
+            Palindrome::count_between(Palindrome(121), Palindrome(121))
+        );
+        assert_eq!(
+            Palindrome::max_index(),
+            Palindrome::count_between(Palindrome::MIN, Palindrome::MAX)
+        );
+
+        let all: Vec<_> = PalindromeIter::first_n(200).collect();
+        for a in all.iter().step_by(3) {
+            for b in all.iter().step_by(5) {
+                assert_eq!(
+                    PalindromeIter::from(*a, *b).len(),
+                    Palindrome::count_between(*a, *b),
+                    "{a}..{b}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindrome_index_distance() {
+        assert_eq!(
+            3,
+            Palindrome::index_distance(&Palindrome(99), &Palindrome(121))
+        );
+        assert_eq!(
+            -3,
+            Palindrome::index_distance(&Palindrome(121), &Palindrome(99))
+        );
+        assert_eq!(
+            0,
+            Palindrome::index_distance(&Palindrome(121), &Palindrome(121))
+        );
+        assert_eq!(
+            Palindrome::MAX_N as isize,
+            Palindrome::index_distance(&Palindrome::MIN, &Palindrome::MAX)
+        );
+        assert_eq!(
+            -(Palindrome::MAX_N as isize),
+            Palindrome::index_distance(&Palindrome::MAX, &Palindrome::MIN)
+        );
+        let a = Palindrome(12321);
+        for b in PalindromeIter::first_n(2_000) {
+            let distance = Palindrome::index_distance(&a, &b);
+            assert_eq!(Some(b), a.offset(distance));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_offset() {
+        let pal = Palindrome(121);
+        assert_eq!(Some(pal), pal.offset(0));
+        assert_eq!(Some(Palindrome(131)), pal.offset(1));
+        assert_eq!(Some(Palindrome(111)), pal.offset(-1));
+        assert_eq!(Some(Palindrome(88)), pal.offset(-4));
+        assert_eq!(Some(Palindrome(202)), pal.offset(8));
+        assert_eq!(Some(Palindrome::MIN), pal.offset(-(pal.to_n() as isize)));
+        assert_eq!(None, pal.offset(-(pal.to_n() as isize) - 1));
+        assert_eq!(None, pal.offset(isize::MIN));
+        assert_eq!(None, pal.offset(isize::MAX));
+        assert_eq!(None, Palindrome::MAX.offset(1));
+        assert_eq!(Some(Palindrome::MAX.previous()), Palindrome::MAX.offset(-1));
+        assert_eq!(None, Palindrome::MIN.offset(-1));
+    }
+
+    #[test]
+    fn test_palindrome_checked_next_previous_n() {
+        let pal = Palindrome(121);
+        assert_eq!(Some(Palindrome(131)), pal.checked_next_n(1));
+        assert_eq!(Some(Palindrome(202)), pal.checked_next_n(8));
+        assert_eq!(Some(Palindrome(111)), pal.checked_previous_n(1));
+        assert_eq!(Some(Palindrome::MIN), pal.checked_previous_n(pal.to_n()));
+        assert_eq!(None, pal.checked_previous_n(pal.to_n() + 1));
+        assert_eq!(Some(Palindrome::MAX), Palindrome::MAX.checked_next_n(0));
+        assert_eq!(None, Palindrome::MAX.checked_next_n(1));
+        assert_eq!(None, Palindrome::MIN.checked_previous_n(1));
+        assert_eq!(None, pal.checked_next_n(usize::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_saturating_next_previous_n() {
+        let pal = Palindrome(121);
+        assert_eq!(Palindrome(131), pal.saturating_next_n(1));
+        assert_eq!(Palindrome(111), pal.saturating_previous_n(1));
+        assert_eq!(Palindrome::MAX, pal.saturating_next_n(usize::MAX));
+        assert_eq!(Palindrome::MIN, pal.saturating_previous_n(usize::MAX));
+        assert_eq!(Palindrome::MAX, Palindrome::MAX.saturating_next_n(1));
+        assert_eq!(Palindrome::MIN, Palindrome::MIN.saturating_previous_n(1));
+    }
+
+    #[test]
+    fn test_palindrome_wrapping_next_previous_n() {
+        let pal = Palindrome(121);
+        assert_eq!(Palindrome(131), pal.wrapping_next_n(1));
+        assert_eq!(Palindrome(111), pal.wrapping_previous_n(1));
+        assert_eq!(Palindrome::MIN, Palindrome::MAX.wrapping_next_n(1));
+        assert_eq!(Palindrome(1), Palindrome::MAX.wrapping_next_n(2));
+        assert_eq!(Palindrome::MAX, Palindrome::MIN.wrapping_previous_n(1));
+        assert_eq!(
+            Palindrome::MAX.previous(),
+            Palindrome::MIN.wrapping_previous_n(2)
+        );
+        assert_eq!(pal, pal.wrapping_next_n(Palindrome::MAX_N + 1));
+        assert_eq!(pal, pal.wrapping_previous_n(Palindrome::MAX_N + 1));
+        assert_eq!(
+            pal.wrapping_next_n(usize::MAX % (Palindrome::MAX_N + 1)),
+            pal.wrapping_next_n(usize::MAX)
+        );
+        for n in 0..1_000 {
+            assert_eq!(pal, pal.wrapping_next_n(n).wrapping_previous_n(n));
+        }
+    }
+
+    #[test]
+    fn test_palindrome_try_next_n() {
+        assert_eq!(Ok(Palindrome(22)), Palindrome(22).try_next_n(0));
+        assert_eq!(Ok(Palindrome(33)), Palindrome(22).try_next_n(1));
+        assert_eq!(Ok(Palindrome(101)), Palindrome(0).try_next_n(19));
+        assert_eq!(
+            Ok(Palindrome::MAX),
+            Palindrome::MIN.try_next_n(Palindrome::MAX_N)
+        );
+        assert_eq!(Err(1), Palindrome::MIN.try_next_n(Palindrome::MAX_N + 1));
+        assert_eq!(Err(1), Palindrome::MAX.try_next_n(1));
+        assert_eq!(
+            Ok(Palindrome::MAX),
+            Palindrome::MAX.previous().try_next_n(1)
+        );
+        assert_eq!(Err(9), Palindrome::MAX.previous().try_next_n(10));
+        assert_eq!(Err(usize::MAX), Palindrome::MAX.try_next_n(usize::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_strictly_below() {
+        assert_eq!(9, Palindrome::strictly_below(11));
+        assert_eq!(11, Palindrome::strictly_below(12));
+        assert_eq!(99, Palindrome::strictly_below(101));
+        assert_eq!(0, Palindrome::strictly_below(1));
+        assert_eq!(Palindrome::MIN, Palindrome::strictly_below(0));
+        assert_eq!(Palindrome::MAX, Palindrome::strictly_below(u64::MAX));
+    }
+
+    #[test]
+    fn test_palindrome_strictly_above() {
+        assert_eq!(22, Palindrome::strictly_above(11));
+        assert_eq!(11, Palindrome::strictly_above(10));
+        assert_eq!(101, Palindrome::strictly_above(99));
+        assert_eq!(1, Palindrome::strictly_above(0));
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::strictly_above(Palindrome::MAX.previous().0)
+        );
+        assert_eq!(
+            Palindrome::MAX,
+            Palindrome::strictly_above(Palindrome::MAX.0)
+        );
+        assert_eq!(Palindrome::MAX, Palindrome::strictly_above(u64::MAX));
+    }
+
     #[test]
     fn test_palindrome_le() {
         assert_eq!(9, Palindrome::le(10));
@@ -1076,6 +8279,49 @@ mod tests {
         assert_eq!(34543, Palindrome::le(34550));
     }
 
+    #[test]
+    fn test_palindrome_mirror() {
+        assert_eq!(1221, Palindrome::mirror(1234));
+        assert_eq!(12321, Palindrome::mirror(12345));
+        assert_eq!(12321, Palindrome::mirror(12399));
+        assert_eq!(0, Palindrome::mirror(0));
+        assert_eq!(7, Palindrome::mirror(7));
+        assert_eq!(11, Palindrome::mirror(10));
+        assert_eq!(99, Palindrome::mirror(90));
+        assert_eq!(1001, Palindrome::mirror(1000));
+        assert_eq!(121, Palindrome::mirror(121));
+        assert_eq!(Palindrome::MAX, Palindrome::mirror(Palindrome::MAX.0));
+        assert_eq!(Palindrome::MAX, Palindrome::mirror(Palindrome::MAX.0 + 1));
+        assert_eq!(Palindrome::MAX, Palindrome::mirror(u64::MAX));
+        assert_eq!(
+            Palindrome::MAX.previous(),
+            Palindrome::mirror(Palindrome::MAX.previous().0 + 1)
+        );
+
+        for x in 0..100_000u64 {
+            let mirrored = Palindrome::mirror(x);
+            assert_eq!(x.to_string().len(), mirrored.to_string().len(), "{x}");
+            assert_eq!(Palindrome::mirror(mirrored.0), mirrored, "{x}");
+            let half = x.to_string().len().div_ceil(2);
+            assert_eq!(x.to_string()[..half], mirrored.to_string()[..half], "{x}");
+        }
+    }
+
+    #[test]
+    fn test_palindrome_le_ge_closest_smallest_inputs() {
+        for x in 0..=9 {
+            assert_eq!(x, Palindrome::le(x), "{x}");
+            assert_eq!(x, Palindrome::ge(x), "{x}");
+            assert_eq!(x, Palindrome::closest(x), "{x}");
+        }
+        assert_eq!(9, Palindrome::le(10));
+        assert_eq!(11, Palindrome::ge(10));
+        assert_eq!(11, Palindrome::closest(10)); // Tie, so the higher one.
+        assert_eq!(11, Palindrome::le(11));
+        assert_eq!(11, Palindrome::ge(11));
+        assert_eq!(11, Palindrome::closest(11));
+    }
+
     #[test]
     fn test_palindrome_ge() {
         assert_eq!(11, Palindrome::ge(10));
@@ -1115,6 +8361,12 @@ mod tests {
         // Fifth test.
         let n = Palindrome::MAX_N + 1; // Max + 1 palindromes.
         let pal_iter = PalindromeIter::first_n(n);
+        assert_eq!(n, pal_iter.len());
+        assert_eq!(Palindrome::MAX, pal_iter.last().unwrap());
+
+        // Sixth test.
+        let n = Palindrome::MAX_N + 2; // Beyond MAX.
+        let pal_iter = PalindromeIter::first_n(n);
         assert_eq!(n - 1, pal_iter.len());
 
         // Test start to end values
@@ -1155,6 +8407,196 @@ mod tests {
         assert_eq!(111u64, pal_iter.next().unwrap());
     }
 
+    #[test]
+    fn test_palindromeiter_checked_first_n_from() {
+        let pal_iter = PalindromeIter::checked_first_n_from(10, Palindrome(22)).unwrap();
+        assert_eq!(10, pal_iter.len());
+        assert_eq!(Some(Palindrome(111)), pal_iter.last());
+        assert_eq!(
+            0,
+            PalindromeIter::checked_first_n_from(0, Palindrome::MAX)
+                .unwrap()
+                .len()
+        );
+
+        // Near the top of the range.
+        let below_max = Palindrome::MAX.previous();
+        let pal_iter = PalindromeIter::checked_first_n_from(2, below_max).unwrap();
+        assert_eq!(
+            vec![below_max, Palindrome::MAX],
+            pal_iter.collect::<Vec<_>>()
+        );
+        assert!(PalindromeIter::checked_first_n_from(3, below_max).is_none());
+        assert!(PalindromeIter::checked_first_n_from(1, Palindrome::MAX).is_some());
+        assert!(PalindromeIter::checked_first_n_from(2, Palindrome::MAX).is_none());
+        assert!(PalindromeIter::checked_first_n_from(usize::MAX, Palindrome(11)).is_none());
+        let all = Palindrome::max_index() + 1;
+        assert_eq!(
+            all,
+            PalindromeIter::checked_first_n_from(all, Palindrome::MIN)
+                .unwrap()
+                .len()
+        );
+        assert!(PalindromeIter::checked_first_n_from(all + 1, Palindrome::MIN).is_none());
+
+        // The unchecked version is truncated instead, without overflowing.
+        assert_eq!(2, PalindromeIter::first_n_from(usize::MAX, below_max).len());
+    }
+
+    #[test]
+    fn test_palindromeiter_last_n_ending_at() {
+        let pal_iter = PalindromeIter::last_n_ending_at(3, Palindrome(121));
+        assert_eq!(3, pal_iter.len());
+        assert_eq!(
+            vec![Palindrome(101), Palindrome(111), Palindrome(121)],
+            pal_iter.collect::<Vec<_>>()
+        );
+
+        let pal_iter = PalindromeIter::last_n_ending_at(1, Palindrome(121));
+        assert_eq!(vec![Palindrome(121)], pal_iter.collect::<Vec<_>>());
+        assert!(PalindromeIter::last_n_ending_at(0, Palindrome(121)).is_empty());
+        assert_eq!(
+            0,
+            PalindromeIter::last_n_ending_at(0, Palindrome(121)).count()
+        );
+
+        // Clamped at Palindrome::MIN.
+        let pal_iter = PalindromeIter::last_n_ending_at(100, Palindrome(11));
+        assert_eq!(11, pal_iter.len());
+        assert_eq!(
+            PalindromeIter::first_n(11).collect::<Vec<_>>(),
+            pal_iter.collect::<Vec<_>>()
+        );
+        let pal_iter = PalindromeIter::last_n_ending_at(usize::MAX, Palindrome::MIN);
+        assert_eq!(vec![Palindrome::MIN], pal_iter.collect::<Vec<_>>());
+
+        let pal_iter = PalindromeIter::last_n_ending_at(2, Palindrome::MAX);
+        assert_eq!(
+            vec![Palindrome::MAX.previous(), Palindrome::MAX],
+            pal_iter.collect::<Vec<_>>()
+        );
+
+        for n in 0..50 {
+            let to = Palindrome::closest(12_345);
+            let pal_iter = PalindromeIter::last_n_ending_at(n, to);
+            assert_eq!(n, pal_iter.len());
+            assert_eq!(n > 0, pal_iter.last() == Some(to));
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_first_n_until() {
+        // Bounded by count.
+        let pal_iter = PalindromeIter::first_n_until(5, Palindrome(22), 1000);
+        assert_eq!(5, pal_iter.len());
+        assert_eq!(vec![22, 33, 44, 55, 66], pal_iter.collect::<Vec<_>>());
+
+        // Bounded by value.
+        let pal_iter = PalindromeIter::first_n_until(100, Palindrome(22), 66);
+        assert_eq!(5, pal_iter.len());
+        assert_eq!(vec![22, 33, 44, 55, 66], pal_iter.collect::<Vec<_>>());
+        let pal_iter = PalindromeIter::first_n_until(100, Palindrome(22), 70);
+        assert_eq!(5, pal_iter.len());
+        assert_eq!(5, pal_iter.count());
+
+        // `from` is above `max`.
+        let pal_iter = PalindromeIter::first_n_until(100, Palindrome(22), 10);
+        assert_eq!(0, pal_iter.len());
+        assert_eq!(0, pal_iter.count());
+
+        // Up to MAX.
+        let pal_iter =
+            PalindromeIter::first_n_until(usize::MAX / 2, Palindrome::MAX.previous(), u64::MAX);
+        assert_eq!(2, pal_iter.len());
+        assert_eq!(Palindrome::MAX, pal_iter.last().unwrap());
+    }
+
+    #[test]
+    fn test_palindromeiter_from_range() {
+        let pal_iter = PalindromeIter::from_range(100..=999);
+        assert_eq!(90, pal_iter.len());
+        assert_eq!(Some(Palindrome(999)), pal_iter.last());
+        assert_eq!(
+            vec![Palindrome(5), Palindrome(6), Palindrome(7)],
+            PalindromeIter::from_range(5..8).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Palindrome(5), Palindrome(6), Palindrome(7), Palindrome(8)],
+            PalindromeIter::from_range(5..=8).collect::<Vec<_>>()
+        );
+        assert_eq!(10, PalindromeIter::from_range(..10).len());
+        assert_eq!(11, PalindromeIter::from_range(..=11).len());
+        assert_eq!(
+            Palindrome::max_index() + 1,
+            PalindromeIter::from_range(..).len()
+        );
+        assert_eq!(
+            Some(Palindrome::MAX),
+            PalindromeIter::from_range(Palindrome::MAX.0..).last()
+        );
+        assert_eq!(
+            1,
+            PalindromeIter::from_range(Palindrome::MAX.0..=u64::MAX).len()
+        );
+        assert_eq!(0, PalindromeIter::from_range(Palindrome::MAX.0 + 1..).len());
+        assert_eq!(0, PalindromeIter::from_range(10..10).len());
+        assert_eq!(
+            0,
+            PalindromeIter::from_range((
+                std::ops::Bound::Excluded(u64::MAX),
+                std::ops::Bound::Unbounded
+            ))
+            .len()
+        );
+        assert_eq!(
+            1,
+            PalindromeIter::from_range((
+                std::ops::Bound::Excluded(9),
+                std::ops::Bound::Included(11)
+            ))
+            .len()
+        );
+
+        for from in 0..120u64 {
+            for to in from.saturating_sub(3)..130 {
+                let inclusive = PalindromeIter::from_range(from..=to);
+                let expected = Palindrome::count(from..=to);
+                assert_eq!(expected, inclusive.len(), "{from}..={to}");
+                assert_eq!(expected, inclusive.count(), "{from}..={to}");
+                let exclusive = PalindromeIter::from_range(from..to);
+                assert_eq!(Palindrome::count(from..to), exclusive.len(), "{from}..{to}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_from_empty() {
+        let mut pal_iter = PalindromeIter::from(100u64, 50u64);
+        assert!(pal_iter.is_empty());
+        assert_eq!(0, pal_iter.len());
+        assert_eq!(None, pal_iter.next());
+        assert_eq!(None, pal_iter.next_back());
+
+        // `12` starts at `22`, which is already past the end.
+        let pal_iter = PalindromeIter::from(12u64, 15u64);
+        assert!(pal_iter.is_empty());
+        assert_eq!(0, pal_iter.count());
+
+        let pal_iter = PalindromeIter::from(Palindrome(121), Palindrome(11));
+        assert!(pal_iter.is_empty());
+        assert_eq!(None, pal_iter.last());
+
+        let pal_iter = PalindromeIter::from(Palindrome::MAX.0 + 1, u64::MAX);
+        assert!(pal_iter.is_empty());
+        assert_eq!(0, pal_iter.count());
+
+        let pal_iter = PalindromeIter::from(u64::MAX, 0u64);
+        assert!(pal_iter.is_empty());
+
+        let pal_iter = PalindromeIter::from(Palindrome::MAX, u64::MAX);
+        assert_eq!(vec![Palindrome::MAX], pal_iter.collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_palindromeiter_len() {
         // 10.
@@ -1191,4 +8633,782 @@ mod tests {
         let pal_iter = PalindromeIter::from(0u64, 668u64);
         assert_eq!(pal_iter.len(), pal_iter.count());
     }
+
+    #[test]
+    fn test_palindrome_max_index() {
+        assert_eq!(11_844_674_405, Palindrome::max_index());
+        assert_eq!(Palindrome::MAX.to_n(), Palindrome::max_index());
+        assert_eq!(
+            Some(Palindrome::MAX),
+            Palindrome::nth(Palindrome::max_index())
+        );
+        assert_eq!(None, Palindrome::nth(Palindrome::max_index() + 1));
+        assert_eq!(11, Palindrome::max_index_digits());
+        assert_eq!(
+            Palindrome::max_index().to_string().len() as u32,
+            Palindrome::max_index_digits()
+        );
+    }
+
+    #[test]
+    fn test_palindrome_max_constants() {
+        use crate::IsPalindrome;
+
+        assert!(Palindrome::MAX.0.is_palindrome());
+        assert_eq!(Palindrome::MAX, Palindrome::MAX.next());
+        assert_eq!(Palindrome::MAX, Palindrome::le(u64::MAX));
+        assert_eq!(Palindrome::MAX_N, Palindrome::MAX.to_n());
+        assert_eq!(Some(Palindrome::MAX), Palindrome::nth(Palindrome::MAX_N));
+        assert_eq!(None, Palindrome::nth(Palindrome::MAX_N + 1));
+        assert_eq!(Palindrome::MAX_LEN, Palindrome::MAX.0.to_string().len());
+
+        // Independently of `le`, mirror the first half of `u64::MAX` and step down once.
+        let half = u64::MAX.to_string()[..10].parse::<u64>().unwrap();
+        let mirror = |half: u64| {
+            let reversed: String = half.to_string().chars().rev().collect();
+            format!("{half}{reversed}").parse::<u128>().unwrap()
+        };
+        assert!(mirror(half) > u64::MAX as u128);
+        assert_eq!(Palindrome::MAX.0 as u128, mirror(half - 1));
+    }
+
+    #[test]
+    fn test_palindromeiter_includes_max() {
+        let pal_iter = PalindromeIter::from(Palindrome::MAX.previous(), u64::MAX);
+        assert_eq!(2, pal_iter.len());
+        let pals: Vec<Palindrome> = pal_iter.collect();
+        assert_eq!(vec![Palindrome::MAX.previous(), Palindrome::MAX], pals);
+
+        let pal_iter = PalindromeIter::first_n_from(5, Palindrome::MAX);
+        assert_eq!(1, pal_iter.len());
+        assert_eq!(vec![Palindrome::MAX], pal_iter.collect::<Vec<_>>());
+
+        let pal_iter = PalindromeIter::from(Palindrome::MAX.previous(), Palindrome::MAX);
+        assert_eq!(1, pal_iter.count());
+    }
+
+    #[test]
+    fn test_palindromeiter_find_ge() {
+        let mut pal_iter = PalindromeIter::from(100u64, 1000u64);
+        assert_eq!(101, pal_iter.find_ge(0).unwrap());
+        assert_eq!(111, pal_iter.next().unwrap());
+        assert_eq!(343, pal_iter.find_ge(340).unwrap());
+        assert_eq!(353, pal_iter.next().unwrap());
+        assert_eq!(363, pal_iter.find_ge(363).unwrap());
+        assert_eq!(None, pal_iter.find_ge(1000));
+        assert_eq!(None, pal_iter.next());
+
+        let mut pal_iter = PalindromeIter::from(0u64, u64::MAX);
+        assert_eq!(
+            Palindrome::MAX,
+            pal_iter.find_ge(Palindrome::MAX.0).unwrap()
+        );
+        assert_eq!(None, pal_iter.next());
+
+        let mut pal_iter = PalindromeIter::from(0u64, u64::MAX);
+        assert_eq!(None, pal_iter.find_ge(Palindrome::MAX.0 + 1));
+        assert_eq!(None, pal_iter.next());
+
+        let mut pal_iter = PalindromeIter::first_n(1_000_000);
+        let expected = PalindromeIter::first_n(1_000_000).find(|p| *p >= 123_456_789);
+        assert_eq!(expected, pal_iter.find_ge(123_456_789));
+    }
+
+    #[test]
+    fn test_palindromeiter_index_of() {
+        let pal_iter = PalindromeIter::from(5u64, 200u64);
+        assert_eq!(Some(0), pal_iter.index_of(Palindrome(5)));
+        assert_eq!(Some(5), pal_iter.index_of(Palindrome(11)));
+        assert_eq!(Some(23), pal_iter.index_of(Palindrome(191)));
+        assert_eq!(None, pal_iter.index_of(Palindrome(4)));
+        assert_eq!(None, pal_iter.index_of(Palindrome(202)));
+        assert_eq!(
+            None,
+            PalindromeIter::from(5u64, 191u64).index_of(Palindrome(191))
+        );
+        assert_eq!(None, PalindromeIter::first_n(0).index_of(Palindrome(0)));
+
+        let pal_iter = PalindromeIter::first_n_from(3, Palindrome::MAX.previous());
+        assert_eq!(Some(1), pal_iter.index_of(Palindrome::MAX));
+
+        let pal_iter = || PalindromeIter::from(1_000u64, 100_000u64);
+        for palindrome in pal_iter() {
+            assert_eq!(
+                pal_iter().position(|p| p == palindrome),
+                pal_iter().index_of(palindrome)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_take_while_below() {
+        let iter = || PalindromeIter::from(5u64, 200u64);
+        assert_eq!(
+            iter().take_while(|p| p.0 < 100).collect::<Vec<_>>(),
+            iter().take_while_below(100).collect::<Vec<_>>()
+        );
+        assert_eq!(14, iter().take_while_below(100).len());
+        assert_eq!(iter().len(), iter().take_while_below(1_000).len());
+        assert!(iter().take_while_below(5).is_empty());
+        assert!(iter().take_while_below(0).is_empty());
+        assert_eq!(Some(Palindrome(99)), iter().take_while_below(101).last());
+        assert_eq!(Some(Palindrome(101)), iter().take_while_below(102).last());
+    }
+
+    #[test]
+    fn test_palindromeiter_skip_while_below() {
+        let iter = || PalindromeIter::from(5u64, 200u64);
+        assert_eq!(
+            iter().skip_while(|p| p.0 < 100).collect::<Vec<_>>(),
+            iter().skip_while_below(100).collect::<Vec<_>>()
+        );
+        assert_eq!(10, iter().skip_while_below(100).len());
+        assert_eq!(iter().len(), iter().skip_while_below(0).len());
+        assert_eq!(Some(Palindrome(101)), iter().skip_while_below(101).next());
+        assert!(iter().skip_while_below(200).is_empty());
+        assert!(iter().skip_while_below(1_000).is_empty());
+        assert_eq!(0, iter().skip_while_below(1_000).count());
+        assert!(iter().skip_while_below(u64::MAX).is_empty());
+
+        let iter = || PalindromeIter::first_n_from(3, Palindrome::MAX.previous());
+        assert_eq!(
+            vec![Palindrome::MAX],
+            iter()
+                .skip_while_below(Palindrome::MAX.0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, iter().skip_while_below(Palindrome::MAX.0 + 1).count());
+    }
+
+    #[test]
+    fn test_palindromeiter_split_at() {
+        let (left, right) = PalindromeIter::from(5u64, 34u64).split_at(3);
+        assert_eq!(vec![5, 6, 7], left.collect::<Vec<_>>());
+        assert_eq!(vec![8, 9, 11, 22, 33], right.collect::<Vec<_>>());
+
+        let (left, mut right) = PalindromeIter::from(5u64, 34u64).split_at(8);
+        assert_eq!(8, left.len());
+        assert!(right.is_empty());
+        assert_eq!(None, right.next());
+
+        let (left, right) = PalindromeIter::from(5u64, 34u64).split_at(100);
+        assert_eq!(8, left.len());
+        assert!(right.is_empty());
+
+        let (left, right) = PalindromeIter::from(5u64, 34u64).split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(8, right.len());
+
+        let (left, right) = PalindromeIter::first_n_from(5, Palindrome::MAX.previous()).split_at(1);
+        assert_eq!(vec![Palindrome::MAX.previous()], left.collect::<Vec<_>>());
+        assert_eq!(vec![Palindrome::MAX], right.collect::<Vec<_>>());
+
+        let all: Vec<_> = PalindromeIter::from(0u64, 5_000u64).collect();
+        for n in 0..=all.len() {
+            let (left, right) = PalindromeIter::from(0u64, 5_000u64).split_at(n);
+            assert_eq!(n, left.len());
+            assert_eq!(all.len() - n, right.len());
+            assert_eq!(all, left.chain(right).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_chunks() {
+        let chunks = PalindromeIter::first_n(25).chunks(10);
+        assert_eq!(3, chunks.len());
+        let chunks: Vec<Vec<Palindrome>> = chunks.collect();
+        assert_eq!(3, chunks.len());
+        assert_eq!(10, chunks[0].len());
+        assert_eq!(10, chunks[1].len());
+        assert_eq!(5, chunks[2].len());
+        let flattened: Vec<Palindrome> = chunks.into_iter().flatten().collect();
+        assert_eq!(PalindromeIter::first_n(25).collect::<Vec<_>>(), flattened);
+
+        let mut chunks = PalindromeIter::first_n(20).chunks(10);
+        assert_eq!(2, chunks.len());
+        assert_eq!(10, chunks.next().unwrap().len());
+        assert_eq!(1, chunks.len());
+        assert_eq!(10, chunks.next().unwrap().len());
+        assert!(chunks.is_empty());
+        assert_eq!(None, chunks.next());
+
+        let mut chunks = PalindromeIter::first_n(0).chunks(10);
+        assert_eq!(0, chunks.len());
+        assert_eq!(None, chunks.next());
+
+        let mut chunks = PalindromeIter::first_n_from(3, Palindrome::MAX.previous()).chunks(5);
+        assert_eq!(
+            vec![Palindrome::MAX.previous(), Palindrome::MAX],
+            chunks.next().unwrap()
+        );
+        assert_eq!(None, chunks.next());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindromeiter_chunks_panic_on_zero_size() {
+        let _ = PalindromeIter::first_n(10).chunks(0);
+    }
+
+    #[test]
+    fn test_palindromeiter_windows() {
+        let windows: Vec<Vec<Palindrome>> = PalindromeIter::from(7u64, 30u64).windows(3).collect();
+        assert_eq!(
+            vec![
+                vec![Palindrome(7), Palindrome(8), Palindrome(9)],
+                vec![Palindrome(8), Palindrome(9), Palindrome(11)],
+                vec![Palindrome(9), Palindrome(11), Palindrome(22)],
+            ],
+            windows
+        );
+
+        let all: Vec<Palindrome> = PalindromeIter::first_n(50).collect();
+        for size in 1..=52 {
+            let mut windows = PalindromeIter::first_n(50).windows(size);
+            assert_eq!(50usize.saturating_sub(size - 1), windows.len(), "{size}");
+            assert_eq!((windows.len(), Some(windows.len())), windows.size_hint());
+            let expected: Vec<&[Palindrome]> = all.windows(size).collect();
+            for (idx, window) in expected.iter().enumerate() {
+                assert_eq!(expected.len() - idx, windows.len());
+                assert_eq!(Some(window.to_vec()), windows.next(), "{size}");
+            }
+            assert!(windows.is_empty());
+            assert_eq!(None, windows.next());
+        }
+
+        let mut windows = PalindromeIter::first_n(0).windows(1);
+        assert!(windows.is_empty());
+        assert_eq!(None, windows.next());
+
+        let windows: Vec<Vec<Palindrome>> =
+            PalindromeIter::from(Palindrome::MAX.previous(), u64::MAX)
+                .windows(2)
+                .collect();
+        assert_eq!(
+            vec![vec![Palindrome::MAX.previous(), Palindrome::MAX]],
+            windows
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_palindromeiter_windows_panic_on_zero_size() {
+        let _ = PalindromeIter::first_n(10).windows(0);
+    }
+
+    #[test]
+    fn test_palindromeiter_merge() {
+        let merged = PalindromeIter::from(0u64, 100u64).merge(PalindromeIter::from(50u64, 200u64));
+        assert_eq!(29, merged.len());
+        let merged: Vec<Palindrome> = merged.collect();
+        assert_eq!(
+            PalindromeIter::from(0u64, 200u64).collect::<Vec<_>>(),
+            merged
+        );
+
+        // Disjoint ranges, with the later range first.
+        let mut merged =
+            PalindromeIter::from(1_000u64, 1_200u64).merge(PalindromeIter::from(5u64, 23u64));
+        assert_eq!(9, merged.len());
+        assert_eq!(Some(Palindrome(5)), merged.next());
+        assert_eq!(8, merged.len());
+        let rest: Vec<Palindrome> = merged.collect();
+        assert_eq!(
+            vec![
+                Palindrome(6),
+                Palindrome(7),
+                Palindrome(8),
+                Palindrome(9),
+                Palindrome(11),
+                Palindrome(22),
+                Palindrome(1001),
+                Palindrome(1111)
+            ],
+            rest
+        );
+
+        // One range contains the other.
+        let merged =
+            PalindromeIter::from(0u64, 10_000u64).merge(PalindromeIter::from(100u64, 200u64));
+        assert_eq!(PalindromeIter::from(0u64, 10_000u64).len(), merged.len());
+        assert_eq!(
+            PalindromeIter::from(0u64, 10_000u64).len(),
+            merged.collect::<Vec<_>>().len()
+        );
+
+        // Empty iterators.
+        let merged = PalindromeIter::first_n(0).merge(PalindromeIter::first_n(5));
+        assert_eq!(5, merged.len());
+        assert_eq!(
+            PalindromeIter::first_n(5).collect::<Vec<_>>(),
+            merged.collect::<Vec<_>>()
+        );
+        let merged = PalindromeIter::first_n(5).merge(PalindromeIter::first_n(0));
+        assert_eq!(5, merged.count());
+        let mut merged = PalindromeIter::first_n(0).merge(PalindromeIter::first_n(0));
+        assert!(merged.is_empty());
+        assert_eq!(None, merged.next());
+
+        // Both ranges end at Palindrome::MAX.
+        let merged = PalindromeIter::first_n_from(3, Palindrome::MAX.previous().previous())
+            .merge(PalindromeIter::first_n_from(2, Palindrome::MAX.previous()));
+        assert_eq!(3, merged.len());
+        assert_eq!(
+            vec![
+                Palindrome::MAX.previous().previous(),
+                Palindrome::MAX.previous(),
+                Palindrome::MAX
+            ],
+            merged.collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_palindromeiter_next_back() {
+        let mut pal_iter = PalindromeIter::from(5u64, 34u64);
+        assert_eq!(Some(Palindrome(33)), pal_iter.next_back());
+        assert_eq!(Some(Palindrome(5)), pal_iter.next());
+        assert_eq!(Some(Palindrome(22)), pal_iter.next_back());
+        assert_eq!(5, pal_iter.len());
+        assert_eq!(
+            vec![
+                Palindrome(11),
+                Palindrome(9),
+                Palindrome(8),
+                Palindrome(7),
+                Palindrome(6)
+            ],
+            pal_iter.rev().collect::<Vec<_>>()
+        );
+
+        let mut pal_iter = PalindromeIter::first_n_from(2, Palindrome::MAX.previous());
+        assert_eq!(Some(Palindrome::MAX), pal_iter.next_back());
+        assert_eq!(Some(Palindrome::MAX.previous()), pal_iter.next_back());
+        assert_eq!(None, pal_iter.next_back());
+        assert_eq!(None, pal_iter.next());
+
+        let mut pal_iter = PalindromeIter::first_n(0);
+        assert_eq!(None, pal_iter.next_back());
+
+        let forward: Vec<Palindrome> = PalindromeIter::from(0u64, 100_000u64).collect();
+        let mut backward: Vec<Palindrome> = PalindromeIter::from(0u64, 100_000u64).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_palindromeiter_nth_back() {
+        let mut pal_iter = PalindromeIter::from(5u64, 34u64);
+        assert_eq!(Some(Palindrome(33)), pal_iter.nth_back(0));
+        assert_eq!(Some(Palindrome(11)), pal_iter.nth_back(1));
+        assert_eq!(5, pal_iter.len());
+        assert_eq!(Some(Palindrome(5)), pal_iter.nth_back(4));
+        assert_eq!(None, pal_iter.next());
+
+        let mut pal_iter = PalindromeIter::from(5u64, 34u64);
+        assert_eq!(None, pal_iter.nth_back(8));
+        assert!(pal_iter.is_empty());
+
+        let mut pal_iter = PalindromeIter::first_n_from(3, Palindrome::MAX.previous());
+        assert_eq!(Some(Palindrome::MAX.previous()), pal_iter.nth_back(1));
+        assert_eq!(None, pal_iter.next_back());
+
+        for n in 0..200 {
+            assert_eq!(
+                PalindromeIter::from(0u64, 2_000u64)
+                    .rev()
+                    .collect::<Vec<_>>()
+                    .get(n)
+                    .copied(),
+                PalindromeIter::from(0u64, 2_000u64).nth_back(n)
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_rev_with_index() {
+        let pal_iter = PalindromeIter::from(100u64, 125u64).rev_with_index();
+        assert_eq!(3, pal_iter.len());
+        assert_eq!(
+            vec![
+                (21, Palindrome(121)),
+                (20, Palindrome(111)),
+                (19, Palindrome(101))
+            ],
+            pal_iter.collect::<Vec<_>>()
+        );
+
+        let pal_iter = PalindromeIter::first_n(0).rev_with_index();
+        assert!(pal_iter.is_empty());
+        assert_eq!(0, pal_iter.count());
+
+        let mut pal_iter =
+            PalindromeIter::first_n_from(2, Palindrome::MAX.previous()).rev_with_index();
+        assert_eq!(Some((Palindrome::MAX_N, Palindrome::MAX)), pal_iter.next());
+        assert_eq!(
+            Some((Palindrome::MAX_N - 1, Palindrome::MAX.previous())),
+            pal_iter.next()
+        );
+        assert_eq!(None, pal_iter.next());
+
+        let mut forward: Vec<(usize, Palindrome)> =
+            PalindromeIter::first_n_from(500, Palindrome(1001))
+                .enumerate()
+                .map(|(i, p)| (Palindrome(1001).to_n() + i, p))
+                .collect();
+        forward.reverse();
+        assert_eq!(
+            forward,
+            PalindromeIter::first_n_from(500, Palindrome(1001))
+                .rev_with_index()
+                .collect::<Vec<_>>()
+        );
+        for (index, palindrome) in PalindromeIter::first_n(1_000).rev_with_index() {
+            assert_eq!(Some(palindrome), Palindrome::nth(index));
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_with_remaining() {
+        assert_eq!(
+            vec![(Palindrome(5), 2), (Palindrome(6), 1), (Palindrome(7), 0)],
+            PalindromeIter::from(5u64, 8u64)
+                .with_remaining()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            None,
+            PalindromeIter::from(5u64, 5u64).with_remaining().next()
+        );
+
+        let mut iter = PalindromeIter::from(0u64, 10_000u64).with_remaining();
+        assert_eq!(199, iter.len());
+        let mut expected = PalindromeIter::from(0u64, 10_000u64);
+        while let Some((palindrome, remaining)) = iter.next() {
+            assert_eq!(expected.next(), Some(palindrome));
+            assert_eq!(expected.len(), remaining);
+            assert_eq!(remaining, iter.len());
+        }
+        assert!(iter.is_empty());
+        assert_eq!(None, iter.next());
+
+        let mut iter = PalindromeIter::first_n_from(3, Palindrome::MAX.previous()).with_remaining();
+        assert_eq!(2, iter.len());
+        assert_eq!(Some((Palindrome::MAX.previous(), 1)), iter.next());
+        assert_eq!(Some((Palindrome::MAX, 0)), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn test_palindromeiter_step_by_value() {
+        assert_eq!(
+            vec![0, 55, 111, 161],
+            PalindromeIter::from(0u64, 200u64)
+                .step_by_value(50)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            PalindromeIter::from(0u64, 1_000u64).collect::<Vec<_>>(),
+            PalindromeIter::from(0u64, 1_000u64)
+                .step_by_value(0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Palindrome(5)],
+            PalindromeIter::from(5u64, 34u64)
+                .step_by_value(u64::MAX)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            None,
+            PalindromeIter::from(5u64, 5u64).step_by_value(1).next()
+        );
+
+        let mut iter = PalindromeIter::first_n_from(3, Palindrome::MAX.previous()).step_by_value(1);
+        assert_eq!(Some(Palindrome::MAX.previous()), iter.next());
+        assert_eq!(Some(Palindrome::MAX), iter.next());
+        assert_eq!(None, iter.next());
+        assert!(iter.is_empty());
+
+        for gap in [1, 2, 7, 50, 333, 10_000] {
+            let iter = PalindromeIter::from(3u64, 50_000u64).step_by_value(gap);
+            assert!(iter.len() >= iter.count());
+            let mut iter = PalindromeIter::from(3u64, 50_000u64).step_by_value(gap);
+            let mut previous: Option<Palindrome> = None;
+            loop {
+                let len = iter.len();
+                let Some(palindrome) = iter.next() else {
+                    assert_eq!(0, len);
+                    break;
+                };
+                assert!(len > iter.len());
+                if let Some(previous) = previous {
+                    assert!(palindrome.0 - previous.0 >= gap);
+                    assert_eq!(Palindrome::ge(previous.0 + gap), palindrome);
+                } else {
+                    assert_eq!(Palindrome(3), palindrome);
+                }
+                previous = Some(palindrome);
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_values() {
+        let values: Vec<u64> = PalindromeIter::from(5u64, 34u64).values().collect();
+        assert_eq!(vec![5, 6, 7, 8, 9, 11, 22, 33], values);
+
+        let mut values = PalindromeIter::first_n(20).values();
+        assert_eq!(20, values.len());
+        assert_eq!((20, Some(20)), values.size_hint());
+        assert_eq!(Some(0), values.next());
+        assert_eq!(19, values.len());
+        assert_eq!(Some(101), values.last());
+
+        let values = PalindromeIter::first_n(0).values();
+        assert!(values.is_empty());
+        assert_eq!(0, values.count());
+
+        let values: Vec<u64> = PalindromeIter::first_n_from(5, Palindrome::MAX.previous())
+            .values()
+            .collect();
+        assert_eq!(
+            vec![Palindrome::MAX.previous().0, Palindrome::MAX.0],
+            values
+        );
+    }
+
+    #[test]
+    fn test_palindromeiter_map_values() {
+        let doubled: Vec<u64> = PalindromeIter::from(5u64, 34u64)
+            .map_values(|x| x * 2)
+            .collect();
+        assert_eq!(vec![10, 12, 14, 16, 18, 22, 44, 66], doubled);
+
+        let mut strings = PalindromeIter::first_n(20).map_values(|x| x.to_string());
+        assert_eq!(20, strings.len());
+        assert_eq!((20, Some(20)), strings.size_hint());
+        assert_eq!(Some(String::from("0")), strings.next());
+        assert_eq!(19, strings.len());
+        assert_eq!(Some(String::from("101")), strings.last());
+
+        let mut calls = 0;
+        let mapped = PalindromeIter::first_n(0).map_values(|x| {
+            calls += 1;
+            x
+        });
+        assert!(mapped.is_empty());
+        assert_eq!(0, mapped.count());
+        assert_eq!(0, calls);
+
+        let mapped = PalindromeIter::from(0u64, u64::MAX).map_values(|x| x % 7);
+        assert_eq!(Palindrome::max_index() + 1, mapped.len());
+    }
+
+    #[test]
+    fn test_palindromeiter_collect_values() {
+        let values = PalindromeIter::from(5u64, 34u64).collect_values();
+        assert_eq!(vec![5, 6, 7, 8, 9, 11, 22, 33], values);
+        assert_eq!(8, values.capacity());
+        assert!(PalindromeIter::from(5u64, 5u64).collect_values().is_empty());
+        assert_eq!(
+            vec![Palindrome::MAX.previous().0, Palindrome::MAX.0],
+            PalindromeIter::first_n_from(5, Palindrome::MAX.previous()).collect_values()
+        );
+        assert_eq!(
+            PalindromeIter::first_n(10_000).values().collect::<Vec<_>>(),
+            PalindromeIter::first_n(10_000).collect_values()
+        );
+    }
+
+    #[test]
+    fn test_palindromeiter_join() {
+        assert_eq!(
+            "5, 6, 7, 8, 9, 11, 22, 33",
+            PalindromeIter::from(5u64, 34u64).join(", ")
+        );
+        assert_eq!("0123", PalindromeIter::first_n(4).join(""));
+        assert_eq!("", PalindromeIter::first_n(0).join(", "));
+        assert_eq!("7", PalindromeIter::from(7u64, 8u64).join(", "));
+        assert_eq!(
+            format!("{} {}", Palindrome::MAX.previous(), Palindrome::MAX),
+            PalindromeIter::first_n_from(2, Palindrome::MAX.previous()).join(" ")
+        );
+
+        let joined = PalindromeIter::from(0u64, 100_000u64).join(" ");
+        let expected = PalindromeIter::from(0u64, 100_000u64)
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(expected, joined);
+    }
+
+    #[test]
+    fn test_palindromeiter_write_to() {
+        let mut out = Vec::new();
+        let count = PalindromeIter::from(5u64, 34u64)
+            .write_to(&mut out, b", ")
+            .unwrap();
+        assert_eq!(8, count);
+        assert_eq!(b"5, 6, 7, 8, 9, 11, 22, 33".as_slice(), out.as_slice());
+
+        let mut out = Vec::new();
+        let count = PalindromeIter::from(10u64, 11u64)
+            .write_to(&mut out, b"\n")
+            .unwrap();
+        assert_eq!(0, count);
+        assert!(out.is_empty());
+
+        let mut out = Vec::new();
+        let count = PalindromeIter::first_n_from(1, Palindrome::MAX)
+            .write_to(&mut out, b"\n")
+            .unwrap();
+        assert_eq!(1, count);
+        assert_eq!(Palindrome::MAX.to_string().as_bytes(), out.as_slice());
+
+        let mut out = Vec::new();
+        PalindromeIter::first_n(1000)
+            .write_to(&mut out, b" ")
+            .unwrap();
+        let expected: Vec<String> = PalindromeIter::first_n(1000)
+            .map(|p| p.to_string())
+            .collect();
+        assert_eq!(expected.join(" ").as_bytes(), out.as_slice());
+    }
+
+    #[test]
+    fn test_palindromeiter_to_mask() {
+        use crate::IsPalindrome;
+
+        let pal_iter = PalindromeIter::from(0u64, 100_000u64);
+        for (from, to) in [
+            (0u64, 1000u64),
+            (95, 205),
+            (12_000, 13_000),
+            (99_990, 100_010),
+        ] {
+            let mask = pal_iter.to_mask(from, to);
+            assert_eq!((to - from) as usize, mask.len());
+            for (idx, is_pal) in mask.iter().enumerate() {
+                let x = from + idx as u64;
+                assert_eq!(x.is_palindrome() && x < 100_000, *is_pal, "{x}");
+            }
+        }
+
+        // Only palindromes in the iterator are marked.
+        let mask = PalindromeIter::from(100u64, 200u64).to_mask(90, 210);
+        assert!(!mask[99 - 90]);
+        assert!(mask[101 - 90]);
+        assert!(mask[191 - 90]);
+        assert!(!mask[202 - 90]);
+
+        assert!(PalindromeIter::first_n(100).to_mask(50, 50).is_empty());
+        assert!(PalindromeIter::first_n(100).to_mask(50, 10).is_empty());
+        let mask = PalindromeIter::from(0u64, u64::MAX).to_mask(u64::MAX - 10, u64::MAX);
+        assert_eq!(vec![false; 10], mask);
+        let mask =
+            PalindromeIter::from(0u64, u64::MAX).to_mask(Palindrome::MAX.0, Palindrome::MAX.0 + 2);
+        assert_eq!(vec![true, false], mask);
+    }
+
+    #[test]
+    fn test_palindromeiter_digit_length_histogram() {
+        let histogram = PalindromeIter::from(0u64, 1000u64).digit_length_histogram();
+        assert_eq!(vec![(1, 10), (2, 9), (3, 90)], histogram);
+
+        // Partial buckets at both ends.
+        let histogram = PalindromeIter::from(5u64, 151u64).digit_length_histogram();
+        assert_eq!(vec![(1, 5), (2, 9), (3, 5)], histogram);
+
+        for (from, to) in [(0u64, 0u64), (0, 1), (11, 12), (98, 10_203), (555, 123_456)] {
+            let pal_iter = PalindromeIter::from(from, to);
+            let mut expected: Vec<(u32, usize)> = Vec::new();
+            for p in PalindromeIter::from(from, to) {
+                let length = p.to_string().len() as u32;
+                match expected.last_mut() {
+                    Some((l, count)) if *l == length => *count += 1,
+                    _ => expected.push((length, 1)),
+                }
+            }
+            assert_eq!(expected, pal_iter.digit_length_histogram(), "{from} {to}");
+        }
+
+        let histogram = PalindromeIter::first_n(Palindrome::MAX_N + 1).digit_length_histogram();
+        assert_eq!(20, histogram.len());
+        assert_eq!((20, Palindrome::MAX_N + 1 - 10_999_999_999), histogram[19]);
+        assert_eq!(
+            Palindrome::MAX_N + 1,
+            histogram.iter().map(|(_, count)| count).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn test_palindromeiter_product_values() {
+        assert_eq!(
+            Some(3_991_680),
+            PalindromeIter::from(1u64, 12u64).product_values()
+        );
+        assert_eq!(
+            Some(0),
+            PalindromeIter::from(0u64, 1_000u64).product_values()
+        );
+        assert_eq!(Some(1), PalindromeIter::from(12u64, 20u64).product_values());
+        assert_eq!(
+            Some(121),
+            PalindromeIter::from(121u64, 122u64).product_values()
+        );
+        assert_eq!(None, PalindromeIter::from(1u64, 1_000u64).product_values());
+        assert_eq!(
+            Some(Palindrome::MAX.previous().0 as u128 * Palindrome::MAX.0 as u128),
+            PalindromeIter::from(Palindrome::MAX.previous(), u64::MAX).product_values()
+        );
+        assert_eq!(
+            None,
+            PalindromeIter::first_n_from(3, Palindrome::MAX.previous().previous()).product_values()
+        );
+        assert_eq!(
+            Some(Palindrome::MAX.0 as u128),
+            PalindromeIter::from(Palindrome::MAX, u64::MAX).product_values()
+        );
+
+        for to in 1..200u64 {
+            let expected = PalindromeIter::from(1u64, to)
+                .map(|pal| pal.0 as u128)
+                .try_fold(1u128, |product, x| product.checked_mul(x));
+            assert_eq!(
+                expected,
+                PalindromeIter::from(1u64, to).product_values(),
+                "{to}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_palindromeiter_sum_values() {
+        let ranges = [
+            (0u64, 0u64),
+            (0, 1),
+            (0, 10),
+            (3, 11),
+            (0, 100),
+            (53, 101),
+            (0, 668),
+            (745, 1000),
+            (569, 10_000),
+            (12_321, 12_322),
+            (98_734, 1_234_567),
+        ];
+        for (from, to) in ranges {
+            let pal_iter = PalindromeIter::from(from, to);
+            let expected: u128 = PalindromeIter::from(from, to).map(|p| *p as u128).sum();
+            assert_eq!(expected, pal_iter.sum_values());
+        }
+
+        // Sum of every palindrome.
+        let pal_iter = PalindromeIter::first_n(Palindrome::MAX_N);
+        let below_max = pal_iter.sum_values();
+        assert!(below_max > u64::MAX as u128);
+        let last = PalindromeIter::from(Palindrome::MAX.previous(), Palindrome::MAX);
+        assert_eq!(Palindrome::MAX.previous().0 as u128, last.sum_values());
+    }
 }
@@ -0,0 +1,191 @@
+use palindromeda::{IsPalindrome, Palindrome, PalindromeIter};
+
+/// Every power of ten that fits in a `u64`, i.e. every point where the amount of digits changes.
+fn powers_of_ten() -> impl Iterator<Item = u64> {
+    (1..=19).map(|exp| 10u64.pow(exp))
+}
+
+/// The values within `radius` of `center` that fit in a `u64`.
+fn around(center: u64, radius: u64) -> impl Iterator<Item = u64> {
+    center.saturating_sub(radius)..=center.saturating_add(radius)
+}
+
+/// Palindromes are sparse among large numbers, so only gaps up to this size are checked value
+/// by value. Larger gaps are checked against `next` and `previous` instead.
+const BRUTE_FORCE_GAP: u64 = 100_000;
+
+/// Check `le`, `ge` and `closest` against the neighbouring palindromes of `x`.
+fn check_le_ge_closest(x: u64) {
+    let le = Palindrome::le(x);
+    assert!(le <= x, "le({x}) = {le}");
+    assert!(le.is_palindrome(), "le({x}) = {le}");
+    if x > *Palindrome::MAX {
+        assert_eq!(Palindrome::MAX, le, "le({x})");
+    } else if x - *le <= BRUTE_FORCE_GAP {
+        assert!(
+            (*le + 1..=x).all(|y| !y.is_palindrome()),
+            "le({x}) = {le} skips a palindrome"
+        );
+    } else {
+        assert!(le.next() > x, "le({x}) = {le} skips a palindrome");
+    }
+
+    let ge = Palindrome::ge(x);
+    if x <= *Palindrome::MAX {
+        assert!(ge >= x, "ge({x}) = {ge}");
+        assert!(ge.is_palindrome(), "ge({x}) = {ge}");
+        if *ge - x <= BRUTE_FORCE_GAP {
+            assert!(
+                (x..*ge).all(|y| !y.is_palindrome()),
+                "ge({x}) = {ge} skips a palindrome"
+            );
+        } else {
+            assert!(ge.previous() < x, "ge({x}) = {ge} skips a palindrome");
+        }
+    } else {
+        assert_eq!(Palindrome::MAX, ge, "ge({x})");
+    }
+
+    let closest = Palindrome::closest(x);
+    if x <= *Palindrome::MAX {
+        let expected = if *ge - x <= x - *le { ge } else { le };
+        assert_eq!(expected, closest, "closest({x})");
+    } else {
+        assert_eq!(Palindrome::MAX, closest, "closest({x})");
+    }
+}
+
+#[test]
+fn le_ge_closest_at_every_length_change() {
+    for x in 0..=100 {
+        check_le_ge_closest(x);
+    }
+    for power in powers_of_ten() {
+        for x in around(power, 50) {
+            check_le_ge_closest(x);
+        }
+        assert_eq!(power - 1, Palindrome::le(power));
+        assert_eq!(power + 1, Palindrome::ge(power));
+        assert_eq!(power + 1, Palindrome::closest(power)); // Tie, so the higher one.
+        assert_eq!(power - 1, Palindrome::closest(power - 1));
+        assert_eq!(power + 1, Palindrome::closest(power + 1));
+    }
+}
+
+#[test]
+fn le_ge_closest_near_u64_max() {
+    for x in around(*Palindrome::MAX, 50) {
+        check_le_ge_closest(x);
+    }
+    for x in u64::MAX - 50..=u64::MAX {
+        check_le_ge_closest(x);
+    }
+    assert_eq!(Palindrome::MAX, Palindrome::le(u64::MAX));
+    assert_eq!(Palindrome::MAX, Palindrome::ge(*Palindrome::MAX + 1));
+    assert_eq!(Palindrome::MAX, Palindrome::closest(u64::MAX));
+}
+
+#[test]
+fn next_and_previous_at_every_length_change() {
+    for digit in 0..9 {
+        assert_eq!(digit + 1, Palindrome::ge(digit).next());
+        assert_eq!(digit, Palindrome::ge(digit + 1).previous());
+    }
+    for power in powers_of_ten() {
+        let largest = Palindrome::le(power);
+        let smallest = Palindrome::ge(power);
+        assert_eq!(smallest, largest.next(), "{largest}");
+        assert_eq!(largest, smallest.previous(), "{smallest}");
+        assert_eq!(largest, largest.next().previous());
+        assert_eq!(smallest, smallest.previous().next());
+        assert_eq!(largest, largest.previous().next());
+        assert_eq!(smallest, smallest.next().previous());
+    }
+}
+
+#[test]
+fn next_and_previous_at_min_and_max() {
+    assert_eq!(Palindrome::MIN, Palindrome::MIN.previous());
+    assert_eq!(1, Palindrome::MIN.next());
+    assert_eq!(Palindrome::MAX, Palindrome::MAX.next());
+    assert_eq!(Palindrome::MAX, Palindrome::MAX.previous().next());
+    assert!(Palindrome::MAX.previous() < Palindrome::MAX);
+    assert_eq!(
+        Palindrome::MAX.previous(),
+        Palindrome::le(*Palindrome::MAX - 1)
+    );
+}
+
+#[test]
+fn nth_and_to_n_at_every_length_change() {
+    assert_eq!(Some(Palindrome::MIN), Palindrome::nth(0));
+    assert_eq!(0, Palindrome::MIN.to_n());
+    for power in powers_of_ten() {
+        let largest = Palindrome::le(power);
+        let smallest = Palindrome::ge(power);
+        assert_eq!(largest.to_n() + 1, smallest.to_n(), "{smallest}");
+        for palindrome in [largest.previous(), largest, smallest, smallest.next()] {
+            assert_eq!(Some(palindrome), Palindrome::nth(palindrome.to_n()));
+        }
+    }
+}
+
+#[test]
+fn nth_and_to_n_at_max() {
+    let max_index = Palindrome::max_index();
+    assert_eq!(max_index, Palindrome::MAX.to_n());
+    assert_eq!(Some(Palindrome::MAX), Palindrome::nth(max_index));
+    assert_eq!(
+        Some(Palindrome::MAX.previous()),
+        Palindrome::nth(max_index - 1)
+    );
+    assert_eq!(None, Palindrome::nth(max_index + 1));
+    assert_eq!(None, Palindrome::nth(usize::MAX));
+}
+
+#[test]
+fn len_at_every_length_change() {
+    for power in powers_of_ten() {
+        assert_eq!(2, PalindromeIter::from(power - 1, power + 2).len());
+        assert_eq!(1, PalindromeIter::from(power - 1, power + 1).len());
+        assert_eq!(1, PalindromeIter::from(power, power + 2).len());
+        assert_eq!(0, PalindromeIter::from(power, power + 1).len());
+        for from in around(power, 5) {
+            for to in around(power, 5) {
+                let expected = (from..to).filter(|x| x.is_palindrome()).count();
+                let pal_iter = PalindromeIter::from(from, to);
+                assert_eq!(expected, pal_iter.len(), "{from}..{to}");
+                assert_eq!(expected, pal_iter.count(), "{from}..{to}");
+            }
+        }
+    }
+}
+
+#[test]
+fn len_near_u64_max() {
+    assert_eq!(1, PalindromeIter::from(Palindrome::MAX, u64::MAX).len());
+    assert_eq!(
+        0,
+        PalindromeIter::from(Palindrome::MAX, Palindrome::MAX).len()
+    );
+    assert_eq!(
+        0,
+        PalindromeIter::from(*Palindrome::MAX + 1, u64::MAX).len()
+    );
+    assert_eq!(
+        2,
+        PalindromeIter::from(Palindrome::MAX.previous(), u64::MAX).len()
+    );
+    assert_eq!(
+        Palindrome::max_index() + 1,
+        PalindromeIter::from(0u64, u64::MAX).len()
+    );
+    assert_eq!(
+        Palindrome::max_index() + 1,
+        PalindromeIter::first_n(Palindrome::max_index() + 1).len()
+    );
+    assert_eq!(
+        vec![Palindrome::MAX.previous(), Palindrome::MAX],
+        PalindromeIter::from(Palindrome::MAX.previous(), u64::MAX).collect::<Vec<_>>()
+    );
+}